@@ -18,15 +18,35 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
+
+/// The type the data was created from, if known.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum DataType {
+    Int,
+    Float,
+    Bool,
+    String,
+    Unknown,
+}
 
 pub struct Data {
     bytes: Vec<u8>,
+    kind: DataType,
 }
 
 impl Clone for Data {
     fn clone(&self) -> Self {
-        Data::from_bytes(self.bytes.clone())
+        Data {
+            bytes: self.bytes.clone(),
+            kind: self.kind,
+        }
+    }
+}
+
+impl PartialEq for Data {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
     }
 }
 
@@ -37,7 +57,14 @@ impl Data {
 
     /// From BYTES.
     pub fn from_bytes(bytes: Vec<u8>) -> Self {
-        Data { bytes }
+        Data {
+            bytes,
+            kind: DataType::Unknown,
+        }
+    }
+
+    fn tagged(bytes: Vec<u8>, kind: DataType) -> Self {
+        Data { bytes, kind }
     }
 
     /// From BYTES as HEX.
@@ -48,27 +75,37 @@ impl Data {
 
     /// From INT.
     pub fn from_int(d: i64) -> Self {
-        Self::from_bytes(d.to_be_bytes().to_vec())
+        Self::tagged(d.to_be_bytes().to_vec(), DataType::Int)
     }
 
     /// From BOOL.
     pub fn from_bool(d: bool) -> Self {
-        Self::from_bytes(if d { [1] } else { [0] }.to_vec())
+        Self::tagged(if d { [1] } else { [0] }.to_vec(), DataType::Bool)
     }
 
     /// From FLOAT.
     pub fn from_float(d: f64) -> Self {
-        Self::from_bytes(d.to_be_bytes().to_vec())
+        Self::tagged(d.to_be_bytes().to_vec(), DataType::Float)
     }
 
     /// From STRING.
     pub fn from_string(d: String) -> Self {
-        Self::from_bytes(d.as_bytes().to_vec())
+        Self::tagged(d.as_bytes().to_vec(), DataType::String)
     }
 
     /// From STR.
     pub fn from_str(d: &str) -> Self {
-        Self::from_bytes(d.to_string().as_bytes().to_vec())
+        Self::tagged(d.to_string().as_bytes().to_vec(), DataType::String)
+    }
+
+    /// The type it was created from, or `Unknown` for raw bytes.
+    pub fn kind(&self) -> DataType {
+        self.kind
+    }
+
+    /// Total number of bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
     }
 
     /// It's empty and no data?
@@ -81,7 +118,7 @@ impl Data {
             .bytes
             .as_slice()
             .try_into()
-            .context(format!("There is no data, can't make INT"))?;
+            .context(format!("Can't read i64 from {} bytes", self.bytes.len()))?;
         Ok(i64::from_be_bytes(*a))
     }
 
@@ -90,10 +127,17 @@ impl Data {
             .bytes
             .as_slice()
             .try_into()
-            .context(format!("There is no data, can't make FLOAT"))?;
+            .context(format!("Can't read f64 from {} bytes", self.bytes.len()))?;
         Ok(f64::from_be_bytes(*a))
     }
 
+    pub fn as_bool(&self) -> Result<bool> {
+        match self.bytes.as_slice() {
+            [b] => Ok(*b != 0),
+            _ => Err(anyhow!("Can't read bool from {} bytes", self.bytes.len())),
+        }
+    }
+
     pub fn as_string(&self) -> Result<String> {
         Ok(String::from_utf8(self.bytes.clone())?)
     }
@@ -132,3 +176,41 @@ fn prints_empty_bytes() {
     let d = Data::from_str(txt);
     assert_eq!("--", d.as_hex());
 }
+
+#[test]
+fn keeps_type_tag() {
+    assert_eq!(DataType::Int, Data::from_int(1).kind());
+    assert_eq!(DataType::String, Data::from_str("hi").kind());
+    assert_eq!(
+        DataType::Unknown,
+        Data::from_hex("00-01".to_string()).kind()
+    );
+    assert_eq!(DataType::Float, Data::from_float(1.5).clone().kind());
+}
+
+#[test]
+fn round_trips_payloads() -> Result<()> {
+    assert_eq!(2.5, Data::from_float(2.5).as_float()?);
+    assert!(Data::from_bool(true).as_bool()?);
+    assert!(!Data::from_bool(false).as_bool()?);
+    assert_eq!("hello", Data::from_str("hello").as_string()?);
+    assert_eq!("40-04-00-00-00-00-00-00", Data::from_float(2.5).as_hex());
+    Ok(())
+}
+
+#[test]
+fn rejects_wrong_sizes() {
+    let d = Data::from_hex("01-02-03".to_string());
+    assert_eq!(
+        "Can't read f64 from 3 bytes",
+        d.as_float().unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Can't read i64 from 3 bytes",
+        d.as_int().unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Can't read bool from 3 bytes",
+        d.as_bool().unwrap_err().to_string()
+    );
+}
@@ -61,12 +61,19 @@ impl Gmi {
     /// number of GMI instructions deployed.
     pub fn deploy_to(&mut self, uni: &mut Universe) -> Result<u32> {
         let txt = &self.text.clone();
-        let lines = txt.split("\n").map(|t| t.trim()).filter(|t| !t.is_empty());
+        let lines = txt
+            .split("\n")
+            .map(|t| t.trim())
+            .enumerate()
+            .filter(|(_, t)| !t.is_empty() && !t.starts_with('#'));
         let mut total = 0;
-        for (pos, t) in lines.enumerate() {
+        for (pos, t) in lines {
             trace!("#deploy_to: deploying line no.{} '{}'...", pos + 1, t);
-            self.deploy_one(t, uni)
-                .context(format!("Failure at the line no.{}: '{}'", pos, t))?;
+            self.deploy_one(t, uni).context(format!(
+                "Failure at the line no.{}: '{}'",
+                pos + 1,
+                t
+            ))?;
             total += 1;
         }
         Ok(total)
@@ -76,10 +83,11 @@ impl Gmi {
     fn deploy_one(&mut self, line: &str, uni: &mut Universe) -> Result<()> {
         lazy_static! {
             static ref LINE: Regex = Regex::new(
-                "^([A-Z]+) *\\( *((?:(?: *, *)?(?:'|\")(?:[^'\"]+)(?:'|\"))* *\\)) *; *(?:#.*)?$"
+                "^([A-Z]+) *\\( *((?:(?: *, *)?(?:(?:'|\")(?:[^'\"]+)(?:'|\")|[^'\", ()]+))* *\\)) *;? *(?:#.*)?$"
             )
             .unwrap();
-            static ref ARGS: Regex = Regex::new("(?: *, *)?(?:'|\")([^\"'']+)(?:'|\")").unwrap();
+            static ref ARGS: Regex =
+                Regex::new("(?: *, *)?(?:(?:'|\")([^\"'']+)(?:'|\")|([^'\", ()]+))").unwrap();
             static ref LOC: Regex = Regex::new("(^|\\.)\\$").unwrap();
         }
         let cap = LINE
@@ -87,8 +95,23 @@ impl Gmi {
             .context(format!("Can't parse '{}'", line))?;
         let args: Vec<&str> = ARGS
             .captures_iter(&cap[2])
-            .map(|c| c.get(1).unwrap().as_str())
+            .map(|c| c.get(1).or_else(|| c.get(2)).unwrap().as_str())
             .collect();
+        let arity = match &cap[1] {
+            "ADD" => 1,
+            "DATA" | "ATOM" => 2,
+            "COPY" => 3,
+            "BIND" | "REFF" => 4,
+            _cmd => return Err(anyhow!("Unknown GMI: {}", _cmd)),
+        };
+        if args.len() != arity {
+            return Err(anyhow!(
+                "{} expects {} arguments, while {} given",
+                &cap[1],
+                arity,
+                args.len()
+            ));
+        }
         match &cap[1] {
             "ADD" => {
                 let v = self.parse(&args[0], uni)?;
@@ -104,6 +127,14 @@ impl Gmi {
                     &args[0], &args[1], &args[2]
                 ))
             }
+            "REFF" => {
+                let e = self.parse(args[0], uni)?;
+                let v1 = self.parse(args[1], uni)?;
+                uni.reff(e, v1, args[2], args[3]).context(format!(
+                    "Failed to REFF({}, {}, {})",
+                    args[0], args[1], args[2]
+                ))
+            }
             "COPY" => {
                 let e1 = self.parse(&args[0], uni)?;
                 let v3 = self.parse(&args[1], uni)?;
@@ -121,10 +152,10 @@ impl Gmi {
             "ATOM" => {
                 let v = self.parse(&args[0], uni)?;
                 let m = &args[1];
-                uni.atom(v, m)
+                uni.atom_named(v, m)
                     .context(format!("Failed to ATOM({})", &args[0]))
             }
-            _cmd => Err(anyhow!("Unknown GMI: {}", _cmd)),
+            _ => unreachable!(),
         }
     }
 
@@ -159,11 +190,14 @@ impl Gmi {
         Ok(data)
     }
 
-    /// Parses `ε2` or `ν5` into `2` and `5` respectively.
+    /// Parses `ε2` or `ν5` into `2` and `5` respectively, while
+    /// plain `7` stays `7`.
     fn parse(&mut self, s: &str, uni: &mut Universe) -> Result<u32> {
         let head = s.chars().next().context(format!("Empty identifier"))?;
         let tail: String = s.chars().skip(1).collect::<Vec<_>>().into_iter().collect();
-        if head == '$' {
+        if head.is_ascii_digit() {
+            Ok(u32::from_str(s).context(format!("Parsing of '{}' failed", s))?)
+        } else if head == '$' {
             Ok(*self
                 .vars
                 .entry(tail.to_string())
@@ -207,6 +241,45 @@ fn deploys_simple_commands() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn deploys_numbers_and_comments() -> Result<()> {
+    let uni: &mut Universe = &mut Universe::empty();
+    Gmi::from_string(
+        "
+        # The root and its kids
+        ADD(0);
+        ADD(1);
+        BIND(10, 0, 1, \"int\");
+        ADD('ν2')
+        BIND('ε20', 'ν1', 'ν2', 'x');
+
+        REFF(30, 0, \"ν1.x\", \"y\"); # an alias
+        "
+        .to_string(),
+    )?
+    .deploy_to(uni)?;
+    assert_eq!(2, uni.find(0, "y")?);
+    assert_eq!(2, uni.find(0, "int.x")?);
+    Ok(())
+}
+
+#[test]
+fn reports_broken_lines() -> Result<()> {
+    for (text, msg) in [
+        ("ADD(0);\nFOO(1);", "line no.2"),
+        ("ADD(0);\n\nADD(1, 2);", "line no.3"),
+        ("ADD(x);", "line no.1"),
+        ("ADD(0);\nBIND(10, 0, 7, \"z\");", "line no.2"),
+    ] {
+        let err = Gmi::from_string(text.to_string())?
+            .deploy_to(&mut Universe::empty())
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains(msg), "{}", err);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 fn all_apps() -> Result<Vec<String>> {
     let mut apps = Vec::new();
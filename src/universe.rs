@@ -18,19 +18,55 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+mod absorb;
+mod coalesce;
+mod compile;
+mod contract;
 mod dataize;
+mod delete;
+mod diff;
+mod dot;
+mod fresh;
+mod graphml;
 mod i_add;
 mod i_atom;
 mod i_bind;
 mod i_copy;
 mod i_data;
+mod i_reff;
+mod incidence;
+mod intern;
+mod kids;
+mod labels;
+mod layers;
+mod metrics;
+mod objects;
+mod recompute;
+mod remap;
+mod replace;
+mod sexpr;
+mod signature;
+mod size;
+mod split;
+mod stats;
+mod structure;
+mod subgraph;
+mod topo;
+mod transpose;
+mod types;
+
+pub use diff::Diff;
+pub use stats::Stats;
 
 use crate::data::Data;
-use anyhow::Result;
+use crate::gmi::Gmi;
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
 use log::error;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
+#[derive(Clone)]
 struct Edge {
     from: u32,
     to: u32,
@@ -41,12 +77,43 @@ impl Edge {
     fn new(from: u32, to: u32, a: String) -> Edge {
         Edge { from, to, a }
     }
+
+    /// Is it a backward edge, labeled as `"ρ"` or `"𝜎"`?
+    fn backward(&self) -> bool {
+        self.a == "ρ" || self.a == "𝜎"
+    }
 }
 
 pub type Error = String;
 
 pub type Lambda = fn(&mut Universe, v: u32) -> Result<u32>;
 
+/// Categories of operations, which are logged by the Universe.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Verbosity {
+    /// Nothing is logged.
+    Silent,
+    /// Only mutations, like `add`, `bind`, or `data`, are logged.
+    Mutations,
+    /// Only traversals, made by `find` and `dataize`, are logged.
+    Traversal,
+    /// Everything is logged.
+    All,
+}
+
+impl Verbosity {
+    /// Shall mutations be logged?
+    fn mutations(&self) -> bool {
+        matches!(self, Verbosity::Mutations | Verbosity::All)
+    }
+
+    /// Shall traversals be logged?
+    fn traversal(&self) -> bool {
+        matches!(self, Verbosity::Traversal | Verbosity::All)
+    }
+}
+
+#[derive(Clone)]
 struct Vertex {
     data: Option<Data>,
     lambda: Option<Lambda>,
@@ -61,22 +128,24 @@ impl Vertex {
             search: "".to_string(),
         }
     }
-
-    /// Make a copy of itself.
-    pub fn clone(&self) -> Self {
-        Vertex {
-            data: self.data.clone(),
-            lambda: self.lambda.clone(),
-            search: self.search.clone(),
-        }
-    }
 }
 
+#[derive(Clone)]
 pub struct Universe {
     vertices: HashMap<u32, Vertex>,
     edges: HashMap<u32, Edge>,
     atoms: HashMap<String, Lambda>,
     tick: u32,
+    hits: HashMap<u32, usize>,
+    verbosity: Verbosity,
+    max_deref: usize,
+    deref_depth: usize,
+    resolving: HashSet<(u32, String)>,
+    auto_repair: bool,
+    warn_dead_ends: bool,
+    cache: HashMap<(u32, String), (Data, Vec<u32>)>,
+    epoch: u64,
+    trail: Option<Vec<u32>>,
 }
 
 impl fmt::Debug for Universe {
@@ -109,9 +178,42 @@ impl Universe {
             edges: HashMap::new(),
             atoms: HashMap::new(),
             tick: 0,
+            hits: HashMap::new(),
+            verbosity: Verbosity::All,
+            max_deref: 64,
+            deref_depth: 0,
+            resolving: HashSet::new(),
+            auto_repair: false,
+            warn_dead_ends: false,
+            cache: HashMap::new(),
+            epoch: 0,
+            trail: None,
         }
     }
 
+    /// Make a new Universe from a script of GMI instructions, one per line,
+    /// like `ADD(0);` or `BIND(2, 0, 1, "x");`, see `Gmi`. In order to
+    /// deploy a script to a Universe with registered atoms, use
+    /// `Gmi::deploy_to` instead.
+    pub fn from_script(text: &str) -> Result<Universe> {
+        let mut uni = Universe::empty();
+        Gmi::from_string(text.to_string())?.deploy_to(&mut uni)?;
+        Ok(uni)
+    }
+
+    /// Forget all data previously found by `dataize`, so that it will be
+    /// found again, walking the graph and firing atoms.
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+
+    /// Remember that the graph has changed: the cache of `dataize`
+    /// is not valid anymore.
+    fn touch(&mut self) {
+        self.epoch += 1;
+        self.cache.clear();
+    }
+
     /// Generates the next available ID for vertices and edges.
     pub fn next_id(&mut self) -> u32 {
         loop {
@@ -128,16 +230,90 @@ impl Universe {
         self.atoms.insert(name.to_string(), m);
     }
 
+    /// Add all atoms registered in the `other` Universe to this one.
+    /// If an atom is registered in both of them under the same name,
+    /// the lambda of the `other` one replaces ours, but only if `overwrite`
+    /// is set. Otherwise, an error is returned and nothing is changed.
+    pub fn merge_registries(&mut self, other: &Universe, overwrite: bool) -> Result<()> {
+        if !overwrite {
+            if let Some(name) = other
+                .atoms
+                .keys()
+                .sorted()
+                .find(|n| self.atoms.contains_key(*n))
+            {
+                return Err(anyhow!("Atom '{}' is already registered", name));
+            }
+        }
+        for (name, m) in other.atoms.iter() {
+            self.atoms.insert(name.clone(), *m);
+        }
+        Ok(())
+    }
+
+    /// Choose which categories of operations are logged. By default,
+    /// everything is logged.
+    pub fn set_verbosity(&mut self, v: Verbosity) {
+        self.verbosity = v;
+    }
+
+    /// Set the maximum length of a chain of aliases, which `find`
+    /// may follow. By default, it is 64.
+    pub fn set_max_deref(&mut self, max: usize) {
+        self.max_deref = max;
+        self.touch();
+    }
+
+    /// Turn on or off the automatic repair of dangling edges found by
+    /// `find` on its way, see `find` for details. By default, it's off.
+    pub fn set_auto_repair(&mut self, on: bool) {
+        self.auto_repair = on;
+        self.touch();
+    }
+
+    /// Turn on or off the reporting of dead ends, see `dead_ends`,
+    /// by `inconsistencies`. By default, it's off.
+    pub fn set_warn_dead_ends(&mut self, on: bool) {
+        self.warn_dead_ends = on;
+    }
+
     /// Validate the Universe and return all found data
-    /// inconsistencies. This is mostly used for testing.
+    /// inconsistencies. This is mostly used for testing. Edges, which
+    /// arrive to or depart from absent vertices, are reported, as well as
+    /// self-loops and targets of forward edges without `ρ` edges back
+    /// (except targets of `π` edges, made by `copy`). Dead ends are
+    /// reported too, if `set_warn_dead_ends` is on. Edges are kept
+    /// by their IDs, that's why two edges can't share an ID.
     pub fn inconsistencies(&self) -> Vec<String> {
         let mut errors = Vec::new();
-        for e in self.edges.iter() {
-            if !self.vertices.contains_key(&e.1.to) {
-                errors.push(format!("Edge ε{} arrives to lost ν{}", e.0, e.1.to));
+        for (i, e) in self.edges.iter().sorted_by_key(|(i, _)| **i) {
+            if !self.vertices.contains_key(&e.to) {
+                errors.push(format!("Edge ε{} points to missing vertex ν{}", i, e.to));
+            } else if !e.backward() && e.a != "π" && self.edge(e.to, "ρ").is_none() {
+                errors.push(format!(
+                    "Edge ε{} arrives to ν{}, which has no ρ edge",
+                    i, e.to
+                ));
             }
-            if !self.vertices.contains_key(&e.1.from) {
-                errors.push(format!("Edge ε{} departs from lost ν{}", e.0, e.1.from));
+            if !self.vertices.contains_key(&e.from) {
+                errors.push(format!(
+                    "Edge ε{} departs from missing vertex ν{}",
+                    i, e.from
+                ));
+            }
+        }
+        for e in self.self_loops() {
+            errors.push(format!(
+                "Edge ε{} is a self-loop at ν{}",
+                e, self.edges[&e].from
+            ));
+        }
+        if self.warn_dead_ends {
+            for e in self.dead_ends() {
+                errors.push(format!(
+                    "Edge ε{} is a dead end at ν{}",
+                    e, self.edges[&e].to
+                ));
             }
         }
         for e in errors.to_vec() {
@@ -145,6 +321,38 @@ impl Universe {
         }
         errors
     }
+
+    /// Find all edges, which depart from and arrive to the same
+    /// vertex. Returns their IDs, ordered.
+    pub fn self_loops(&self) -> Vec<u32> {
+        self.edges
+            .iter()
+            .filter(|(_, e)| e.from == e.to)
+            .map(|(i, _)| *i)
+            .sorted()
+            .collect()
+    }
+
+    /// Find all edges, which arrive to empty vertices: without data,
+    /// without atoms, and without outgoing edges, except `ρ` and `𝜎`.
+    /// Returns their IDs, ordered. Such edges are reported by
+    /// `inconsistencies` only if `set_warn_dead_ends` is on, since they
+    /// are normal while the Universe is still being built.
+    pub fn dead_ends(&self) -> Vec<u32> {
+        self.edges
+            .iter()
+            .filter(|(_, e)| match self.vertices.get(&e.to) {
+                Some(vtx) => {
+                    vtx.data.is_none()
+                        && vtx.lambda.is_none()
+                        && !self.edges.values().any(|x| x.from == e.to && !x.backward())
+                }
+                None => false,
+            })
+            .map(|(i, _)| *i)
+            .sorted()
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -198,3 +406,126 @@ fn generates_random_int() -> Result<()> {
     assert_ne!(first, second);
     Ok(())
 }
+
+#[test]
+fn clones_deeply() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(10, 0, 1, "x")?;
+    uni.data(1, Data::from_int(42))?;
+    let snapshot = uni.clone();
+    uni.add(2)?;
+    uni.data(1, Data::from_int(7))?;
+    assert_eq!(2, snapshot.vertices.len());
+    assert_eq!(42, snapshot.clone().dataize("Φ.x")?.as_int()?);
+    uni = snapshot;
+    assert_eq!(42, uni.dataize("Φ.x")?.as_int()?);
+    assert!(uni.inconsistencies().is_empty());
+    Ok(())
+}
+
+#[test]
+fn works_silently() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.set_verbosity(Verbosity::Silent);
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "x")?;
+    uni.data(v1, Data::from_int(42))?;
+    assert!(!uni.verbosity.mutations());
+    assert!(!uni.verbosity.traversal());
+    assert_eq!(42, uni.dataize("Φ.x")?.as_int()?);
+    Ok(())
+}
+
+#[test]
+fn finds_self_loops() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(10, 0, 1, "x")?;
+    assert!(uni.self_loops().is_empty());
+    uni.bind(11, 1, 1, "me")?;
+    assert_eq!(vec![11], uni.self_loops());
+    assert_eq!(1, uni.inconsistencies().len());
+    Ok(())
+}
+
+#[test]
+fn finds_broken_edges() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(10, 0, 1, "x")?;
+    assert!(uni.inconsistencies().is_empty());
+    uni.edges.insert(20, Edge::new(1, 9, "y".to_string()));
+    uni.add(2)?;
+    uni.edges.insert(30, Edge::new(0, 2, "z".to_string()));
+    assert_eq!(
+        vec![
+            "Edge ε20 points to missing vertex ν9",
+            "Edge ε30 arrives to ν2, which has no ρ edge"
+        ],
+        uni.inconsistencies()
+    );
+    Ok(())
+}
+
+#[test]
+fn finds_dead_ends() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(10, 0, 1, "x")?;
+    uni.add(2)?;
+    uni.bind(11, 0, 2, "y")?;
+    uni.data(2, Data::from_int(42))?;
+    uni.add(3)?;
+    uni.bind(12, 0, 3, "z")?;
+    uni.add(4)?;
+    uni.bind(13, 3, 4, "a")?;
+    assert_eq!(vec![10, 13], uni.dead_ends());
+    assert!(uni.inconsistencies().is_empty());
+    uni.set_warn_dead_ends(true);
+    assert_eq!(
+        vec![
+            "Edge ε10 is a dead end at ν1",
+            "Edge ε13 is a dead end at ν4"
+        ],
+        uni.inconsistencies()
+    );
+    Ok(())
+}
+
+#[test]
+fn merges_atom_registries() -> Result<()> {
+    let mut first = Universe::empty();
+    crate::org::eolang::register(&mut first);
+    let mut second = Universe::empty();
+    second.register("rand", rand);
+    first.merge_registries(&second, false)?;
+    assert!(first.atoms.contains_key("rand"));
+    let mut third = Universe::empty();
+    third.register("org.eolang.int.plus", rand);
+    third.register("dummy", rand);
+    let err = first.merge_registries(&third, false).err().unwrap();
+    assert_eq!(
+        "Atom 'org.eolang.int.plus' is already registered",
+        err.to_string()
+    );
+    assert!(!first.atoms.contains_key("dummy"));
+    first.merge_registries(&third, true)?;
+    assert!(first.atoms.contains_key("dummy"));
+    Ok(())
+}
+
+#[test]
+fn builds_from_script() -> Result<()> {
+    let mut uni = Universe::from_script("ADD(0);\nADD(1);\nBIND(10, 0, 1, \"x\");")?;
+    assert_eq!(1, uni.find(0, "x")?);
+    assert!(Universe::from_script("ADD(0);\nFOO(1);").is_err());
+    Ok(())
+}
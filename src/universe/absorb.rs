@@ -0,0 +1,136 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::remap::relocate;
+use crate::universe::{Edge, Universe};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use log::trace;
+use std::collections::HashMap;
+
+impl Universe {
+    /// Move all vertices and edges of the `other` Universe into this one,
+    /// giving them fresh IDs. The root of the `other` one becomes the
+    /// vertex `attach`, getting its edges, and its data and atom, if
+    /// `attach` has none. Atoms registered in the `other` Universe are
+    /// registered here too, replacing ours with the same names. Returns
+    /// the mapping from old vertex IDs to new ones. If something can't
+    /// be absorbed, nothing is changed.
+    pub fn absorb(&mut self, other: Universe, attach: u32) -> Result<HashMap<u32, u32>> {
+        if !self.vertices.contains_key(&attach) {
+            return Err(anyhow!("Can't find ν{}", attach));
+        }
+        for (i, e) in other.edges.iter().sorted_by_key(|(i, _)| **i) {
+            for v in [e.from, e.to] {
+                if !other.vertices.contains_key(&v) {
+                    return Err(anyhow!("Edge ε{} refers to missing vertex ν{}", i, v));
+                }
+            }
+        }
+        for e in other.edges.values().filter(|e| e.from == 0) {
+            if let Some(v) = self.edge(attach, &e.a) {
+                return Err(anyhow!(
+                    "Edge '{}' already exists in ν{}, arriving to ν{}",
+                    e.a,
+                    attach,
+                    v
+                ));
+            }
+        }
+        self.merge_registries(&other, true)?;
+        let mut mapping = HashMap::new();
+        for v in other.vertices.keys().sorted() {
+            let id = if *v == 0 { attach } else { self.next_id() };
+            mapping.insert(*v, id);
+            if *v != 0 {
+                self.add(id)?;
+            }
+        }
+        for (v, vtx) in other.vertices.into_iter() {
+            let target = self.vertices.get_mut(&mapping[&v]).unwrap();
+            if v == 0 && (target.data.is_some() || target.lambda.is_some()) {
+                continue;
+            }
+            target.search = relocate(vtx.search.as_str(), &mapping);
+            target.data = vtx.data;
+            target.lambda = vtx.lambda;
+        }
+        for (_, e) in other.edges.into_iter().sorted_by_key(|(i, _)| *i) {
+            let id = self.next_id();
+            self.edges
+                .insert(id, Edge::new(mapping[&e.from], mapping[&e.to], e.a));
+        }
+        self.touch();
+        if self.verbosity.mutations() {
+            trace!("#absorb(ν{}): {} vertices absorbed", attach, mapping.len());
+        }
+        Ok(mapping)
+    }
+}
+
+#[cfg(test)]
+fn random_universe() -> Result<Universe> {
+    let mut uni = Universe::empty();
+    uni.register("rand", super::rand);
+    uni.add(0)?;
+    uni.bind_atom(0, "x", "rand")?;
+    Ok(uni)
+}
+
+#[test]
+fn absorbs_twice() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    for a in ["int", "left", "right"] {
+        let v = uni.next_id();
+        uni.add(v)?;
+        let e = uni.next_id();
+        uni.bind(e, 0, v, a)?;
+    }
+    let left = uni.find(0, "left")?;
+    let right = uni.find(0, "right")?;
+    let first = uni.absorb(random_universe()?, left)?;
+    let second = uni.absorb(random_universe()?, right)?;
+    assert_eq!(left, first[&0]);
+    assert_ne!(first[&1], second[&1]);
+    assert!(uni.inconsistencies().is_empty());
+    let a = uni.dataize("Φ.left.x")?.as_int()?;
+    let b = uni.dataize("Φ.right.x")?.as_int()?;
+    assert_ne!(a, b);
+    assert!(uni.absorb(random_universe()?, left).is_err());
+    Ok(())
+}
+
+#[test]
+fn rejects_dangling_edges() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    let mut other = random_universe()?;
+    other.edges.insert(100, Edge::new(0, 1, "y".to_string()));
+    other.vertices.remove(&1);
+    other.atoms.clear();
+    other.register("other", super::rand);
+    assert!(uni.absorb(other, 0).is_err());
+    assert_eq!(2, uni.vertices.len());
+    assert!(uni.edges.is_empty());
+    assert!(uni.atoms.is_empty());
+    Ok(())
+}
@@ -0,0 +1,77 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use itertools::Itertools;
+use log::trace;
+use std::collections::HashSet;
+
+impl Universe {
+    /// Remove duplicate `ρ` and `𝜎` edges, which connect the same pair
+    /// of vertices. The edge with the smallest ID survives, the others
+    /// are deleted. Returns the number of edges removed.
+    pub fn coalesce_rho(&mut self) -> usize {
+        let mut seen = HashSet::new();
+        let extra: Vec<u32> = self
+            .edges
+            .iter()
+            .filter(|(_, e)| e.backward())
+            .sorted_by_key(|(i, _)| **i)
+            .filter(|(_, e)| !seen.insert((e.from, e.to, e.a.clone())))
+            .map(|(i, _)| *i)
+            .collect();
+        for e in extra.iter() {
+            self.edges.remove(e);
+            if self.verbosity.mutations() {
+                trace!("#coalesce_rho(): duplicate edge ε{} removed", e);
+            }
+        }
+        if !extra.is_empty() {
+            self.touch();
+        }
+        extra.len()
+    }
+}
+
+#[cfg(test)]
+use crate::universe::Edge;
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn removes_duplicate_rho_edges() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "x")?;
+    for a in ["ρ", "ρ", "𝜎"] {
+        let e = uni.next_id();
+        uni.edges.insert(e, Edge::new(v1, 0, a.to_string()));
+    }
+    assert_eq!(3, uni.coalesce_rho());
+    assert_eq!(0, uni.coalesce_rho());
+    assert_eq!(1, uni.edges_with_label("ρ").len());
+    assert_eq!(1, uni.edges_with_label("𝜎").len());
+    assert_eq!(0, uni.find(v1, "ρ")?);
+    Ok(())
+}
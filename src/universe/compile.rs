@@ -0,0 +1,255 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::data::Data;
+use crate::universe::Universe;
+use anyhow::{anyhow, Context, Result};
+use std::cell::RefCell;
+use std::collections::{HashSet, VecDeque};
+use std::str::FromStr;
+
+/// A step of a compiled locator.
+enum Step {
+    /// Jump to the vertex, like `ν5` or `Φ` does.
+    Jump(u32),
+    /// Go through the edge to the vertex it arrives to.
+    Hop(u32, u32),
+}
+
+impl Universe {
+    /// Resolve the absolute locator `loc` once and return a closure, which
+    /// dataizes it again and again, without searching for the edges
+    /// by their labels. The edges are resolved statically, until an
+    /// atom is met: the rest of the locator is left for `find`, which
+    /// the closure calls every time. If the Universe is modified after
+    /// the compilation, the locator is resolved again by the closure,
+    /// when it's called next time. If one of the resolved edges
+    /// disappears or gets reconnected behind the back of the Universe,
+    /// the closure returns an error.
+    pub fn compile(&self, loc: &str) -> Result<impl Fn(&mut Universe) -> Result<Data>> {
+        let compiled = RefCell::new((self.epoch, self.resolve(loc)?));
+        let loc = loc.to_string();
+        Ok(move |uni: &mut Universe| {
+            if compiled.borrow().0 != uni.epoch {
+                *compiled.borrow_mut() = (uni.epoch, uni.resolve(loc.as_str())?);
+            }
+            let c = compiled.borrow();
+            let (steps, rest) = &c.1;
+            let mut vtx = 0;
+            for step in steps.iter() {
+                match step {
+                    Step::Jump(v) => vtx = *v,
+                    Step::Hop(e, to) => {
+                        let edge = uni
+                            .edges
+                            .get(e)
+                            .context(format!("The graph has changed, ε{} is absent", e))?;
+                        if edge.from != vtx || edge.to != *to {
+                            return Err(anyhow!(
+                                "The graph has changed, ε{} doesn't lead from ν{} to ν{}",
+                                e,
+                                vtx,
+                                to
+                            ));
+                        }
+                        *uni.hits.entry(*e).or_insert(0) += 1;
+                        vtx = *to;
+                    }
+                }
+            }
+            if !rest.is_empty() {
+                vtx = uni.find(vtx, rest.as_str())?;
+            }
+            if !uni.vertices.contains_key(&vtx) {
+                return Err(anyhow!("The graph has changed, ν{} is absent", vtx));
+            }
+            uni.data_of(vtx)
+        })
+    }
+
+    /// Resolve the absolute locator `loc` into the steps to make,
+    /// until an atom is met, and the rest of the locator.
+    fn resolve(&self, loc: &str) -> Result<(Vec<Step>, String)> {
+        let mut steps = vec![];
+        let mut tail = vec![];
+        let mut vtx = 0;
+        let mut seen = HashSet::new();
+        let mut sectors = VecDeque::new();
+        loc.split('.').for_each(|k| sectors.push_back(k));
+        while let Some(k) = sectors.pop_front() {
+            if let Some(id) = k.strip_prefix('ν') {
+                vtx = u32::from_str(id)?;
+                steps.push(Step::Jump(vtx));
+                continue;
+            }
+            if k == "𝜉" {
+                continue;
+            }
+            if k == "Φ" {
+                vtx = 0;
+                steps.push(Step::Jump(vtx));
+                continue;
+            }
+            if k.is_empty() {
+                return Err(anyhow!("The locator is empty"));
+            }
+            if k == "^" {
+                let (e, to) = self
+                    .edge_id(vtx, "ρ")
+                    .context(format!("ν{} has no parent (ρ) edge", vtx))?;
+                steps.push(Step::Hop(e, to));
+                vtx = to;
+                continue;
+            }
+            let (e, to) = match self.edge_id(vtx, k) {
+                Some(p) => p,
+                None => match self.edge_id(vtx, "φ") {
+                    Some(p) => {
+                        sectors.push_front(k);
+                        if !seen.insert((p.1, sectors.len())) {
+                            return Err(anyhow!(
+                                "Cycle detected while resolving '.{}' at ν{}",
+                                k,
+                                p.1
+                            ));
+                        }
+                        p
+                    }
+                    None => {
+                        if self
+                            .vertices
+                            .get(&vtx)
+                            .context(format!("Can't find ν{}", vtx))?
+                            .lambda
+                            .is_some()
+                        {
+                            tail.push(k);
+                            tail.extend(sectors.drain(..));
+                            break;
+                        }
+                        if k == "Δ" {
+                            break;
+                        }
+                        return Err(anyhow!("Can't continue as ν{}.{}", vtx, k));
+                    }
+                },
+            };
+            if !self.vertices.contains_key(&to) {
+                return Err(anyhow!("Can't move to ν{}.{}, ν{} is absent", vtx, k, to));
+            }
+            steps.push(Step::Hop(e, to));
+            vtx = to;
+        }
+        Ok((steps, tail.join(".")))
+    }
+
+    /// Find `k`-labeled edge departing from `v` and return its ID
+    /// together with the number of the vertex it points to.
+    fn edge_id(&self, v: u32, k: &str) -> Option<(u32, u32)> {
+        self.edges
+            .iter()
+            .find(|(_, e)| e.from == v && e.a == k)
+            .map(|(i, e)| (*i, e.to))
+    }
+}
+
+#[test]
+fn replays_compiled_locator() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "x")?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, v1, v2, "y")?;
+    let f = uni.compile("Φ.x.y")?;
+    uni.data(v2, Data::from_int(1))?;
+    assert_eq!(1, f(&mut uni)?.as_int()?);
+    uni.data(v2, Data::from_int(2))?;
+    assert_eq!(2, f(&mut uni)?.as_int()?);
+    assert_eq!(2, uni.compile("Φ.x.y.^.y")?(&mut uni)?.as_int()?);
+    assert!(uni.compile("Φ.^").is_err());
+    uni.edges.remove(&e2);
+    assert!(f(&mut uni).is_err());
+    Ok(())
+}
+
+#[test]
+fn compiles_locator_with_atom() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "int")?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, 0, v2, "x")?;
+    uni.register("rand", super::rand);
+    uni.atom(v2, "rand")?;
+    let f = uni.compile("Φ.x.Δ")?;
+    assert_ne!(f(&mut uni)?.as_int()?, f(&mut uni)?.as_int()?);
+    assert!(uni.compile("Φ.y").is_err());
+    Ok(())
+}
+
+#[test]
+fn rejects_phi_cycle() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.add(2)?;
+    uni.bind(101, 0, 1, "x")?;
+    uni.bind(102, 1, 2, "φ")?;
+    uni.bind(103, 2, 1, "φ")?;
+    let err = uni.compile("Φ.x.foo").err().unwrap();
+    assert!(
+        err.to_string()
+            .starts_with("Cycle detected while resolving '.foo'"),
+        "{}",
+        err
+    );
+    Ok(())
+}
+
+#[test]
+fn recompiles_after_new_edges() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(100, 0, 1, "x")?;
+    uni.add(2)?;
+    uni.bind(101, 0, 2, "z")?;
+    uni.bind(102, 1, 2, "φ")?;
+    uni.add(3)?;
+    uni.bind(103, 2, 3, "y")?;
+    uni.data(3, Data::from_int(1))?;
+    let f = uni.compile("Φ.x.y")?;
+    assert_eq!(1, f(&mut uni)?.as_int()?);
+    uni.add(4)?;
+    uni.bind(104, 1, 4, "y")?;
+    uni.data(4, Data::from_int(2))?;
+    assert_eq!(2, f(&mut uni)?.as_int()?);
+    Ok(())
+}
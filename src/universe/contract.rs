@@ -0,0 +1,156 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use anyhow::{anyhow, Context, Result};
+use log::trace;
+
+impl Universe {
+    /// Removes the vertex `v`, which must have exactly one incoming
+    /// and exactly one outgoing edge, not counting `ρ` and `𝜎` ones. The
+    /// incoming edge is redirected to the vertex, where the outgoing
+    /// one arrives. The vertex must have neither data nor atom. This
+    /// is the opposite of `split_edge`.
+    pub fn contract_vertex(&mut self, v: u32) -> Result<()> {
+        let vtx = self
+            .vertices
+            .get(&v)
+            .context(format!("Can't find ν{}", v))?;
+        if vtx.data.is_some() {
+            return Err(anyhow!("ν{} has data, can't contract it", v));
+        }
+        if vtx.lambda.is_some() {
+            return Err(anyhow!("ν{} has an atom, can't contract it", v));
+        }
+        let ins: Vec<u32> = self
+            .edges
+            .iter()
+            .filter(|(_, e)| e.to == v && !e.backward())
+            .map(|(i, _)| *i)
+            .collect();
+        let outs: Vec<u32> = self
+            .edges
+            .iter()
+            .filter(|(_, e)| e.from == v && !e.backward())
+            .map(|(i, _)| *i)
+            .collect();
+        if ins.len() != 1 || outs.len() != 1 {
+            return Err(anyhow!(
+                "ν{} has {} incoming and {} outgoing edges, can't contract it",
+                v,
+                ins.len(),
+                outs.len()
+            ));
+        }
+        let e1 = ins[0];
+        let e2 = outs[0];
+        let v1 = self
+            .edges
+            .get(&e1)
+            .context(format!("Can't find ε{}", e1))?
+            .from;
+        let v2 = self
+            .edges
+            .get(&e2)
+            .context(format!("Can't find ε{}", e2))?
+            .to;
+        if v1 == v || v2 == v {
+            return Err(anyhow!("ν{} has a self-loop, can't contract it", v));
+        }
+        self.edges.remove(&e2);
+        self.edges
+            .get_mut(&e1)
+            .context(format!("Can't find ε{}", e1))?
+            .to = v2;
+        for e in self
+            .edges
+            .values_mut()
+            .filter(|e| e.from == v2 && e.to == v && e.backward())
+        {
+            e.to = v1;
+        }
+        self.edges.retain(|_, e| e.from != v && e.to != v);
+        self.vertices.remove(&v);
+        if self.verbosity.mutations() {
+            trace!(
+                "#contract_vertex(ν{}): ν{}-ε{}>ν{}-ε{}>ν{} restructured as ν{}-ε{}>ν{}",
+                v,
+                v1,
+                e1,
+                v,
+                e2,
+                v2,
+                v1,
+                e1,
+                v2
+            );
+        }
+        self.touch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use crate::data::Data;
+
+#[test]
+fn contracts_split_edge() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "x")?;
+    uni.data(v1, Data::from_int(42))?;
+    let v2 = uni.split_edge(e1, "x", "y")?;
+    uni.contract_vertex(v2)?;
+    assert!(uni.inconsistencies().is_empty());
+    assert_eq!(v1, uni.find(0, "x")?);
+    assert_eq!(0, uni.find(v1, "ρ")?);
+    assert_eq!(42, uni.dataize("Φ.x")?.as_int()?);
+    Ok(())
+}
+
+#[test]
+fn refuses_to_contract_wrong_vertices() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "x")?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, v1, v2, "a")?;
+    let v3 = uni.next_id();
+    uni.add(v3)?;
+    let e3 = uni.next_id();
+    uni.bind(e3, v1, v3, "b")?;
+    assert!(uni.contract_vertex(v1).is_err());
+    assert!(uni.contract_vertex(v2).is_err());
+    assert!(uni.contract_vertex(0).is_err());
+    let e4 = uni.next_id();
+    uni.bind(e4, v2, v3, "c")?;
+    uni.data(v2, Data::from_int(1))?;
+    assert!(uni.contract_vertex(v2).is_err());
+    assert_eq!(v2, uni.find(0, "x.a")?);
+    Ok(())
+}
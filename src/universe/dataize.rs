@@ -21,41 +21,191 @@
 use crate::data::Data;
 use crate::universe::Universe;
 use anyhow::{anyhow, Context, Result};
+use itertools::Itertools;
 use log::trace;
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
 use std::str::FromStr;
 
 impl Universe {
     /// Dataize by absolute locator. The search always starts from the
     /// root node of the tree. It is recommended to start the locator
     /// from "Φ". If you need to find any vertex starting from non-root
-    /// one, use `find` method.
+    /// one, use `find` method. If the vertex found has no data, but
+    /// has an atom, the atom is fired and its result is dataized.
+    /// The data found is cached until the Universe is changed, unless
+    /// it was changed during the dataization itself (by an atom).
     pub fn dataize(&mut self, loc: &str) -> Result<Data> {
-        let id = self
-            .find(0, loc)
-            .context(format!("Failed to find {}", loc))?;
-        let v = self
-            .vertices
-            .get(&id)
-            .context(format!("ν{} is absent", id))?;
-        let data = v
-            .data
-            .clone()
-            .context(format!("There is no data in ν{}", id))?;
+        self.dataize_at(0, loc)
+    }
+
+    /// Dataize all locators, starting from the vertex `v`, just like
+    /// `dataize` does, one by one. The results are returned in the same
+    /// order, a failure of one locator doesn't stop the others.
+    pub fn dataize_all(&mut self, v: u32, locs: &[&str]) -> Vec<Result<Data>> {
+        locs.iter().map(|loc| self.dataize_at(v, loc)).collect()
+    }
+
+    /// Dataize the locator, starting from the vertex `v`, using
+    /// the cache, if possible. The edges traversed are cached too,
+    /// in order to count them in the metrics, when the cache is used.
+    fn dataize_at(&mut self, v: u32, loc: &str) -> Result<Data> {
+        let key = (v, loc.to_string());
+        if let Some((d, edges)) = self.cache.get(&key) {
+            if self.verbosity.traversal() {
+                trace!("#dataize(ν{}, '{}'): found in cache", v, loc);
+            }
+            let (d, edges) = (d.clone(), edges.clone());
+            for e in edges.iter() {
+                *self.hits.entry(*e).or_insert(0) += 1;
+            }
+            if let Some(outer) = &mut self.trail {
+                outer.extend(edges);
+            }
+            return Ok(d);
+        }
+        let epoch = self.epoch;
+        let outer = self.trail.replace(vec![]);
+        let data = self
+            .find(v, loc)
+            .context(format!("Failed to find {}", loc))
+            .and_then(|id| self.data_of(id));
+        let edges = std::mem::replace(&mut self.trail, outer).unwrap_or_default();
+        if let Some(outer) = &mut self.trail {
+            outer.extend(edges.iter());
+        }
+        let data = data?;
+        if self.epoch == epoch {
+            self.cache.insert(key, (data.clone(), edges));
+        }
         Ok(data)
     }
 
+    /// Take the data of the vertex `v`. If there is no data, but there
+    /// is an atom, fire it and take the data of the vertex it returns,
+    /// and so on, until a vertex with data is met again.
+    pub(super) fn data_of(&mut self, v: u32) -> Result<Data> {
+        let mut id = v;
+        let mut seen = HashSet::new();
+        loop {
+            if !seen.insert(id) {
+                return Err(anyhow!("Cycle detected while dataizing ν{} at ν{}", v, id));
+            }
+            let vtx = self
+                .vertices
+                .get(&id)
+                .context(format!("ν{} is absent", id))?;
+            if let Some(d) = &vtx.data {
+                return Ok(d.clone());
+            }
+            if vtx.lambda.is_none() {
+                return Err(anyhow!("There is no data in ν{}", id));
+            }
+            id = self.fire(id)?;
+        }
+    }
+
+    /// Dataize by absolute locator, just like `dataize` does, but
+    /// before that, temporarily put the `inputs` into the vertices found
+    /// by their absolute locators. When the dataization is over, the
+    /// previous data of the vertices is restored, even if it failed.
+    pub fn dataize_with_inputs(&mut self, loc: &str, inputs: &[(&str, Data)]) -> Result<Data> {
+        let mut saved = vec![];
+        let mut ret = Ok(());
+        for (input, d) in inputs {
+            match self.find(0, input) {
+                Ok(v) => match self.vertices.get_mut(&v) {
+                    Some(vtx) => {
+                        saved.push((v, vtx.data.replace(d.clone())));
+                        self.touch();
+                    }
+                    None => ret = Err(anyhow!("ν{} is absent", v)),
+                },
+                Err(e) => ret = Err(e.context(format!("Failed to find input {}", input))),
+            }
+            if ret.is_err() {
+                break;
+            }
+        }
+        let data = ret.and_then(|_| self.dataize(loc));
+        for (v, d) in saved.into_iter().rev() {
+            if let Some(vtx) = self.vertices.get_mut(&v) {
+                vtx.data = d;
+            }
+            self.touch();
+        }
+        data
+    }
+
+    /// Dataize two absolute locators and compare the data they
+    /// return, byte by byte. Vertices don't matter, only their data.
+    pub fn values_equal(&mut self, loc_a: &str, loc_b: &str) -> Result<bool> {
+        let a = self.dataize(loc_a)?;
+        let b = self.dataize(loc_b)?;
+        Ok(a == b)
+    }
+
     /// Find a vertex in the Universe by its locator. The search
     /// starts from the vertex `v`, but the locator may jump to
-    /// the root vertex, if it starts with "Φ".
+    /// the root vertex, if it starts with "Φ", or go up to the parent
+    /// through the `ρ` edge, with the "^" sector. Search atoms, which
+    /// point to other search atoms, make chains of aliases: if
+    /// such a chain is longer than `max_deref`, an error is returned.
+    /// Aliases met by nested searches, made by search atoms, are
+    /// counted in the same chain.
+    /// If `auto_repair` is on and an edge on the way arrives to an absent
+    /// vertex, all such edges departing from the current vertex are
+    /// deleted and the search continues, instead of failing.
     pub fn find(&mut self, v: u32, loc: &str) -> Result<u32> {
+        self.walk(v, loc, &mut vec![])
+    }
+
+    /// Find a vertex in the Universe by its locator, just like `find`
+    /// does, and return all vertices visited on the way, starting with
+    /// `v` and finishing with the vertex found. If the search fails,
+    /// the error explains which vertices were visited before the failure.
+    pub fn find_path(&mut self, v: u32, loc: &str) -> Result<Vec<u32>> {
+        let mut path = vec![];
+        match self.walk(v, loc, &mut path) {
+            Ok(_) => Ok(path),
+            Err(e) => Err(e.context(format!(
+                "Failed to find '{}' after visiting {}",
+                loc,
+                path.iter().map(|v| format!("ν{}", v)).join(", ")
+            ))),
+        }
+    }
+
+    /// Dataize all vertices visited while an absolute locator is being
+    /// resolved by `find_path`. Returns them together with their data,
+    /// if they have any.
+    pub fn path_data(&mut self, loc: &str) -> Result<Vec<(u32, Option<Data>)>> {
+        Ok(self
+            .find_path(0, loc)?
+            .into_iter()
+            .map(|v| (v, self.vertices.get(&v).and_then(|vtx| vtx.data.clone())))
+            .collect())
+    }
+
+    /// Find a vertex by its locator, remembering all visited vertices
+    /// in the `path`. If a `φ` edge or an atom brings it back to a vertex
+    /// where it already was with the same sectors left, it's a cycle.
+    /// It's a cycle too, if a search atom is fired again, with the same
+    /// sectors left, by a nested search, which it started itself.
+    fn walk(&mut self, v: u32, loc: &str, path: &mut Vec<u32>) -> Result<u32> {
         let mut vtx = v;
+        path.push(vtx);
+        let mut aliases = 0;
+        let mut seen = HashSet::new();
         let mut sectors = VecDeque::new();
         loc.split('.').for_each(|k| sectors.push_back(k));
         loop {
             if let Some(k) = sectors.pop_front() {
-                if k.starts_with("ν") {
-                    vtx = u32::from_str(&k[2..])?;
+                if let Some(num) = k.strip_prefix('ν') {
+                    vtx = u32::from_str(num)
+                        .context(format!("Can't parse vertex id from '{}'", k))?;
+                    if path.last() != Some(&vtx) {
+                        path.push(vtx);
+                    }
                     continue;
                 }
                 if k == "𝜉" {
@@ -64,44 +214,109 @@ impl Universe {
                 }
                 if k == "Φ" {
                     vtx = 0;
+                    if path.last() != Some(&vtx) {
+                        path.push(vtx);
+                    }
                     continue;
                 }
                 if k == "" {
                     return Err(anyhow!("The locator is empty"));
                 }
-                let to = match self.edge(vtx, k) {
-                    Some(v) => v,
+                if k == "^" {
+                    vtx = self
+                        .hop(vtx, "ρ")
+                        .context(format!("ν{} has no parent (ρ) edge", vtx))?;
+                    path.push(vtx);
+                    continue;
+                }
+                let mut pushed = false;
+                let to = match self.hop(vtx, k) {
+                    Some(v) => {
+                        aliases = 0;
+                        v
+                    }
                     None => {
-                        let to = match self.edge(vtx, "φ") {
+                        let to = match self.hop(vtx, "φ") {
                             Some(v) => v,
-                            None => match self
-                                .vertices
-                                .get(&vtx)
-                                .context(format!("Can't find ν{}", vtx))?
-                                .lambda
-                                .clone()
-                            {
-                                Some(m) => {
-                                    let to = m(self, vtx)?;
-                                    trace!("#dataize({}, '{}'): atom returned {}", v, loc, to);
-                                    to
-                                }
-                                None => {
+                            None => {
+                                let target = self
+                                    .vertices
+                                    .get(&vtx)
+                                    .context(format!("Can't find ν{}", vtx))?;
+                                if target.lambda.is_none() {
                                     if k == "Δ" {
                                         return Ok(vtx);
                                     }
                                     return Err(anyhow!("Can't continue as ν{}.{}", vtx, k));
                                 }
-                            },
+                                let alias = !target.search.is_empty();
+                                let state =
+                                    (vtx, sectors.iter().fold(k.to_string(), |a, s| a + "." + s));
+                                if alias {
+                                    if !self.resolving.insert(state.clone()) {
+                                        return Err(anyhow!(
+                                            "Cycle detected while resolving '.{}' at ν{}",
+                                            k,
+                                            vtx
+                                        ));
+                                    }
+                                    aliases += 1;
+                                    if self.deref_depth + aliases > self.max_deref {
+                                        self.resolving.remove(&state);
+                                        return Err(anyhow!(
+                                            "Alias chain exceeded {} hops at ν{}",
+                                            self.max_deref,
+                                            vtx
+                                        ));
+                                    }
+                                    self.deref_depth += 1;
+                                }
+                                let fired = self.fire(vtx);
+                                if alias {
+                                    self.deref_depth -= 1;
+                                    self.resolving.remove(&state);
+                                }
+                                let to = fired?;
+                                if self.verbosity.traversal() {
+                                    trace!("#dataize({}, '{}'): atom returned {}", v, loc, to);
+                                }
+                                to
+                            }
                         };
                         sectors.push_front(k);
+                        pushed = true;
                         to
                     }
                 };
                 if !self.vertices.contains_key(&to) {
-                    return Err(anyhow!("Can't move to ν{}.{}, ν{} is absent", vtx, k, to));
+                    let before = self.edges.len();
+                    if self.auto_repair {
+                        let vertices = &self.vertices;
+                        self.edges
+                            .retain(|_, e| e.from != vtx || vertices.contains_key(&e.to));
+                    }
+                    let pruned = before - self.edges.len();
+                    if pruned == 0 {
+                        return Err(anyhow!("Can't move to ν{}.{}, ν{} is absent", vtx, k, to));
+                    }
+                    self.touch();
+                    if self.verbosity.traversal() {
+                        trace!("#find(ν{}, '{}'): {} dangling edges pruned", v, loc, pruned);
+                    }
+                    if !pushed {
+                        sectors.push_front(k);
+                    }
+                    continue;
                 }
                 vtx = to;
+                path.push(vtx);
+                if pushed && !seen.insert((vtx, sectors.len())) {
+                    return Err(anyhow!(
+                        "Cycle detected while resolving '.{}' at ν{}",
+                        k,
+                        vtx
+                    ));
+                }
             } else {
                 break;
             }
@@ -109,6 +324,45 @@ impl Universe {
         Ok(vtx)
     }
 
+    /// Find a vertex by its locator, just like `find` does, but reject
+    /// suspicious locators instead of resolving them: the ones with
+    /// `Φ` anywhere except the beginning, with more than one `𝜉`,
+    /// or with empty sectors, like in `Φ..x`.
+    pub fn strict_find(&mut self, v: u32, loc: &str) -> Result<u32> {
+        let mut xi = false;
+        for (i, k) in loc.split('.').enumerate() {
+            if k.is_empty() {
+                return Err(anyhow!("The sector no.{} of '{}' is empty", i, loc));
+            }
+            if k == "Φ" && i > 0 {
+                return Err(anyhow!("The root is not at the beginning of '{}'", loc));
+            }
+            if k == "𝜉" {
+                if xi {
+                    return Err(anyhow!("There are many sectors '𝜉' in '{}'", loc));
+                }
+                xi = true;
+            }
+        }
+        self.find(v, loc)
+    }
+
+    /// Call the atom of the vertex `v` exactly once and return the
+    /// number of the vertex it returns, without any further dataization.
+    pub fn fire(&mut self, v: u32) -> Result<u32> {
+        let m = self
+            .vertices
+            .get(&v)
+            .context(format!("Can't find ν{}", v))?
+            .lambda
+            .context(format!("There is no atom in ν{}", v))?;
+        let to = m(self, v)?;
+        if self.verbosity.traversal() {
+            trace!("#fire(ν{}): atom returned ν{}", v, to);
+        }
+        Ok(to)
+    }
+
     /// Find `k`-labeled edge departing from `v` and return the number
     /// of the vertex it points to.
     ///
@@ -117,8 +371,26 @@ impl Universe {
     pub fn edge(&self, v: u32, k: &str) -> Option<u32> {
         Some(self.edges.values().find(|e| e.from == v && e.a == k)?.to)
     }
+
+    /// Find `k`-labeled edge departing from `v`, just like `edge` does,
+    /// and count this traversal of the edge in the metrics.
+    fn hop(&mut self, v: u32, k: &str) -> Option<u32> {
+        let (e, to) = self
+            .edges
+            .iter()
+            .find(|(_, e)| e.from == v && e.a == k)
+            .map(|(i, e)| (*i, e.to))?;
+        *self.hits.entry(e).or_insert(0) += 1;
+        if let Some(trail) = &mut self.trail {
+            trail.push(e);
+        }
+        Some(to)
+    }
 }
 
+#[cfg(test)]
+use crate::universe::Edge;
+
 #[test]
 fn search_atom_works() -> Result<()> {
     let mut uni = Universe::empty();
@@ -139,3 +411,371 @@ fn search_atom_works() -> Result<()> {
     assert_eq!(uni.find(v1, "Φ.a.c")?, v3);
     Ok(())
 }
+
+#[test]
+fn compares_values() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    for (a, d) in [("x", 42), ("y", 42), ("z", 7)] {
+        let v = uni.next_id();
+        uni.add(v)?;
+        let e = uni.next_id();
+        uni.bind(e, 0, v, a)?;
+        uni.data(v, Data::from_int(d))?;
+    }
+    assert!(uni.values_equal("Φ.x", "Φ.y")?);
+    assert!(!uni.values_equal("Φ.x", "Φ.z")?);
+    assert!(uni.values_equal("Φ.x", "Φ.w").is_err());
+    Ok(())
+}
+
+#[test]
+fn fires_atom_once() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "int")?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, 0, v2, "x")?;
+    uni.register("rand", super::rand);
+    uni.atom(v2, "rand")?;
+    let v3 = uni.fire(v2)?;
+    assert_ne!(v2, v3);
+    assert!(uni.vertices.get(&v3).unwrap().data.is_some());
+    assert!(uni.fire(v1).is_err());
+    Ok(())
+}
+
+#[test]
+fn stops_long_alias_chain() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    for v in 1..=70 {
+        uni.add(v)?;
+        uni.atom(v, format!("S/ν{}", v + 1).as_str())?;
+    }
+    uni.add(71)?;
+    uni.data(71, Data::from_int(42))?;
+    let err = uni.find(1, "Δ").unwrap_err().to_string();
+    assert_eq!("Alias chain exceeded 64 hops at ν65", err);
+    uni.set_max_deref(100);
+    assert_eq!(71, uni.find(1, "Δ")?);
+    Ok(())
+}
+
+#[test]
+fn stops_alias_loop() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(1)?;
+    uni.add(2)?;
+    uni.atom(1, "S/ν2")?;
+    uni.atom(2, "S/ν1")?;
+    assert!(uni.find(1, "Δ").is_err());
+    Ok(())
+}
+
+#[test]
+fn stops_nested_alias_chain() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    for v in 1..=10 {
+        uni.add(v)?;
+        uni.atom(v, format!("S/ν{}.Δ", v + 1).as_str())?;
+    }
+    uni.add(11)?;
+    uni.data(11, Data::from_int(42))?;
+    uni.set_max_deref(5);
+    let err = uni.find(1, "Δ").unwrap_err();
+    assert!(
+        format!("{:#}", err).contains("Alias chain exceeded 5 hops"),
+        "{:#}",
+        err
+    );
+    uni.set_max_deref(20);
+    assert_eq!(11, uni.find(1, "Δ")?);
+    Ok(())
+}
+
+#[test]
+fn stops_nested_alias_loop() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(1)?;
+    uni.add(2)?;
+    uni.atom(1, "S/ν2.Δ")?;
+    uni.atom(2, "S/ν1.Δ")?;
+    let err = uni.find(1, "Δ").unwrap_err();
+    assert_eq!("Cycle detected while resolving '.Δ' at ν1", err.to_string());
+    assert_eq!(0, uni.deref_depth);
+    assert!(uni.resolving.is_empty());
+    uni.atom(2, "S/ν3")?;
+    uni.add(3)?;
+    assert_eq!(3, uni.find(1, "Δ")?);
+    Ok(())
+}
+
+#[test]
+fn rejects_suspicious_locators() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "x")?;
+    assert_eq!(v1, uni.strict_find(0, "Φ.x")?);
+    assert_eq!(v1, uni.strict_find(0, "𝜉.x")?);
+    assert_eq!(v1, uni.find(0, "x.Φ.x")?);
+    assert!(uni.strict_find(0, "x.Φ.x").is_err());
+    assert!(uni.strict_find(0, "𝜉.𝜉.x").is_err());
+    assert!(uni.strict_find(0, "Φ..x").is_err());
+    Ok(())
+}
+
+#[test]
+fn dataizes_with_inputs() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "x")?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, 0, v2, "y")?;
+    uni.atom(v2, "S/Φ.x")?;
+    let first = uni.dataize_with_inputs("Φ.y.Δ", &[("Φ.x", Data::from_int(1))])?;
+    assert_eq!(1, first.as_int()?);
+    let second = uni.dataize_with_inputs("Φ.y.Δ", &[("Φ.x", Data::from_int(2))])?;
+    assert_eq!(2, second.as_int()?);
+    assert!(uni.dataize("Φ.x").is_err());
+    assert!(uni
+        .dataize_with_inputs("Φ.z", &[("Φ.x", Data::from_int(3))])
+        .is_err());
+    assert!(uni
+        .dataize_with_inputs("Φ.x", &[("Φ.x", Data::from_int(4)), ("Φ.z", Data::empty())])
+        .is_err());
+    assert!(uni.dataize("Φ.x").is_err());
+    Ok(())
+}
+
+#[test]
+fn collects_path_data() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "x")?;
+    uni.data(v1, Data::from_int(1))?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, v1, v2, "y")?;
+    uni.data(v2, Data::from_int(2))?;
+    let path = uni.path_data("Φ.x.y")?;
+    assert_eq!(
+        vec![0, v1, v2],
+        path.iter().map(|(v, _)| *v).collect::<Vec<u32>>()
+    );
+    assert!(path[0].1.is_none());
+    assert_eq!(1, path[1].1.as_ref().unwrap().as_int()?);
+    assert_eq!(2, path[2].1.as_ref().unwrap().as_int()?);
+    let err = uni.find_path(0, "x.z").unwrap_err().to_string();
+    assert_eq!(
+        format!("Failed to find 'x.z' after visiting ν0, ν{}", v1),
+        err
+    );
+    Ok(())
+}
+
+#[test]
+fn prunes_dangling_edges() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "φ")?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, v1, v2, "x")?;
+    uni.data(v2, Data::from_int(42))?;
+    uni.edges.insert(100, Edge::new(0, 99, "x".to_string()));
+    assert!(uni.dataize("Φ.x").is_err());
+    assert!(!uni.inconsistencies().is_empty());
+    uni.set_auto_repair(true);
+    assert_eq!(42, uni.dataize("Φ.x")?.as_int()?);
+    assert!(uni.inconsistencies().is_empty());
+    Ok(())
+}
+
+#[test]
+fn reports_bad_locators() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    assert!(uni.find(0, "foo.bar").is_err());
+    let err = uni.find(0, "νabc").unwrap_err();
+    assert_eq!("Can't parse vertex id from 'νabc'", err.to_string());
+    let err = uni.dataize("Φ.foo").err().unwrap();
+    assert!(format!("{:#}", err).contains("Can't continue as ν0.foo"));
+    Ok(())
+}
+
+#[test]
+fn detects_phi_cycle() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.add(2)?;
+    uni.bind(101, 0, 1, "x")?;
+    uni.bind(102, 1, 2, "φ")?;
+    uni.bind(103, 2, 1, "φ")?;
+    let err = uni.find(0, "x.next").unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("Cycle detected while resolving '.next'"),
+        "{}",
+        err
+    );
+    assert_eq!(2, uni.find(0, "x.φ")?);
+    uni.add(3)?;
+    uni.bind(104, 2, 3, "next")?;
+    assert_eq!(3, uni.find(0, "x.next")?);
+    Ok(())
+}
+
+#[test]
+fn resolves_diamond() -> Result<()> {
+    let mut uni = Universe::empty();
+    for v in 0..4 {
+        uni.add(v)?;
+    }
+    uni.bind(101, 0, 1, "a")?;
+    uni.bind(102, 0, 2, "b")?;
+    uni.bind(103, 1, 3, "c")?;
+    uni.bind(104, 2, 3, "c")?;
+    uni.bind(105, 3, 3, "next")?;
+    assert_eq!(3, uni.find(0, "a.c.next.next")?);
+    assert_eq!(3, uni.find(0, "b.c.ρ.c")?);
+    Ok(())
+}
+
+#[cfg(test)]
+fn answer(uni: &mut Universe, v: u32) -> Result<u32> {
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e = uni.next_id();
+    uni.bind(e, v, v2, "a")?;
+    uni.data(v2, Data::from_int(42))?;
+    Ok(v2)
+}
+
+#[cfg(test)]
+fn itself(_uni: &mut Universe, v: u32) -> Result<u32> {
+    Ok(v)
+}
+
+#[test]
+fn dataizes_atom_without_data() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.register("answer", answer);
+    uni.register("itself", itself);
+    uni.add(0)?;
+    uni.bind_atom(0, "x", "answer")?;
+    assert_eq!(42, uni.dataize("Φ.x")?.as_int()?);
+    uni.bind_atom(0, "y", "itself")?;
+    let err = uni.dataize("Φ.y").err().unwrap();
+    assert!(err.to_string().starts_with("Cycle detected"), "{}", err);
+    uni.bind_atom(0, "z", "unknown")?;
+    let err = uni.dataize("Φ.z").err().unwrap();
+    assert_eq!("Not implemented yet", err.to_string());
+    Ok(())
+}
+
+#[cfg(test)]
+static COUNTED: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+#[cfg(test)]
+fn counted(uni: &mut Universe, v: u32) -> Result<u32> {
+    COUNTED.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    uni.find(v, "k")
+}
+
+#[test]
+fn caches_dataized_values() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.register("counted", counted);
+    uni.add(0)?;
+    let v1 = uni.bind_atom(0, "x", "counted")?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e = uni.next_id();
+    uni.bind(e, v1, v2, "k")?;
+    uni.data(v2, Data::from_int(42))?;
+    let calls = || COUNTED.load(std::sync::atomic::Ordering::SeqCst);
+    assert_eq!(42, uni.dataize("Φ.x")?.as_int()?);
+    assert_eq!(42, uni.dataize("Φ.x")?.as_int()?);
+    assert_eq!(1, calls());
+    uni.data(v2, Data::from_int(7))?;
+    assert_eq!(7, uni.dataize("Φ.x")?.as_int()?);
+    assert_eq!(2, calls());
+    uni.clear_cache();
+    assert_eq!(7, uni.dataize("Φ.x")?.as_int()?);
+    assert_eq!(3, calls());
+    uni.set_max_deref(8);
+    assert_eq!(7, uni.dataize("Φ.x")?.as_int()?);
+    assert_eq!(4, calls());
+    Ok(())
+}
+
+#[test]
+fn jumps_to_multi_digit_vertex() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(10)?;
+    uni.bind(100, 0, 10, "x")?;
+    assert_eq!(10, uni.find(0, "ν10")?);
+    assert_eq!(0, uni.find(0, "ν10.ρ")?);
+    assert!(uni.find(0, "ν").is_err());
+    assert!(uni.find(0, "ν1x").is_err());
+    Ok(())
+}
+
+#[test]
+fn goes_up_to_parent() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.add(2)?;
+    uni.bind(100, 0, 1, "x")?;
+    uni.bind(101, 1, 2, "y")?;
+    uni.data(1, Data::from_int(42))?;
+    let parent = uni.find(2, "^.Δ")?;
+    assert_eq!(1, parent);
+    assert_eq!(0, uni.find(2, "^.^")?);
+    assert_eq!(2, uni.find(0, "x.y.^.y")?);
+    uni.add(3)?;
+    let err = uni.find(3, "^").unwrap_err();
+    assert_eq!("ν3 has no parent (ρ) edge", err.to_string());
+    Ok(())
+}
+
+#[test]
+fn dataizes_many_locators() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(100, 0, 1, "x")?;
+    uni.data(1, Data::from_int(42))?;
+    let all = uni.dataize_all(0, &["x.Δ", "y.Δ", "x"]);
+    assert_eq!(3, all.len());
+    assert_eq!(42, all[0].as_ref().unwrap().as_int()?);
+    assert!(all[1].is_err());
+    assert_eq!(42, all[2].as_ref().unwrap().as_int()?);
+    Ok(())
+}
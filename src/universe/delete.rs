@@ -0,0 +1,118 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use anyhow::{anyhow, Result};
+use log::trace;
+
+impl Universe {
+    /// Delete the vertex `v` together with all edges, which depart
+    /// from it or arrive to it.
+    pub fn delete_vertex(&mut self, v: u32) -> Result<()> {
+        if self.vertices.remove(&v).is_none() {
+            return Err(anyhow!("Can't find ν{}", v));
+        }
+        let before = self.edges.len();
+        self.edges.retain(|_, e| e.from != v && e.to != v);
+        let edges = &self.edges;
+        self.hits.retain(|e, _| edges.contains_key(e));
+        if self.verbosity.mutations() {
+            trace!(
+                "#delete_vertex(ν{}): vertex deleted with {} edges",
+                v,
+                before - self.edges.len()
+            );
+        }
+        self.touch();
+        Ok(())
+    }
+
+    /// Delete the edge `e`. If it's not a `ρ` or `𝜎` edge itself and
+    /// there are no other edges between the same vertices, its `ρ` and
+    /// `𝜎` edges back are deleted too.
+    pub fn delete_edge(&mut self, e: u32) -> Result<()> {
+        let edge = self
+            .edges
+            .remove(&e)
+            .ok_or_else(|| anyhow!("Can't find ε{}", e))?;
+        self.hits.remove(&e);
+        let mut back = vec![];
+        if !edge.backward()
+            && !self
+                .edges
+                .values()
+                .any(|x| !x.backward() && x.from == edge.from && x.to == edge.to)
+        {
+            back = self
+                .edges
+                .iter()
+                .filter(|(_, x)| x.backward() && x.from == edge.to && x.to == edge.from)
+                .map(|(i, _)| *i)
+                .collect();
+            for b in back.iter() {
+                self.edges.remove(b);
+                self.hits.remove(b);
+            }
+        }
+        if self.verbosity.mutations() {
+            trace!(
+                "#delete_edge(ε{}): edge ν{}-ε{}({})>ν{} deleted with {} edges back",
+                e,
+                edge.from,
+                e,
+                edge.a,
+                edge.to,
+                back.len()
+            );
+        }
+        self.touch();
+        Ok(())
+    }
+}
+
+#[test]
+fn deletes_vertex_with_edges() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.add(2)?;
+    uni.bind(10, 0, 1, "x")?;
+    uni.bind(20, 1, 2, "y")?;
+    uni.delete_vertex(1)?;
+    assert!(!uni.vertices.contains_key(&1));
+    assert!(uni.edges.values().all(|e| e.from != 1 && e.to != 1));
+    assert!(uni.delete_vertex(1).is_err());
+    Ok(())
+}
+
+#[test]
+fn deletes_edge_with_edges_back() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(10, 0, 1, "x")?;
+    uni.bind(20, 0, 1, "y")?;
+    uni.delete_edge(10)?;
+    assert_eq!(0, uni.find(1, "ρ")?);
+    uni.delete_edge(20)?;
+    assert!(uni.edges.is_empty());
+    assert!(uni.delete_edge(20).is_err());
+    Ok(())
+}
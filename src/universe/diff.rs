@@ -0,0 +1,179 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::data::{Data, DataType};
+use crate::universe::Universe;
+use itertools::Itertools;
+
+/// Differences between two Universes, matched by IDs of vertices
+/// and edges. An edge with the same ID, but different ends or label,
+/// is both removed and added.
+#[derive(Default)]
+pub struct Diff {
+    pub added_vertices: Vec<u32>,
+    pub removed_vertices: Vec<u32>,
+    pub changed_data: Vec<(u32, Option<Data>, Option<Data>)>,
+    pub added_edges: Vec<u32>,
+    pub removed_edges: Vec<u32>,
+}
+
+impl Diff {
+    /// Nothing changed?
+    pub fn is_empty(&self) -> bool {
+        self.added_vertices.is_empty()
+            && self.removed_vertices.is_empty()
+            && self.changed_data.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+    }
+}
+
+impl Universe {
+    /// Find what must be changed in this Universe to get the `other` one.
+    /// All lists in the `Diff` are ordered by IDs.
+    pub fn diff(&self, other: &Universe) -> Diff {
+        let mut diff = Diff::default();
+        for v in other.vertices.keys().sorted() {
+            if !self.vertices.contains_key(v) {
+                diff.added_vertices.push(*v);
+            }
+        }
+        for (v, vtx) in self.vertices.iter().sorted_by_key(|(v, _)| **v) {
+            match other.vertices.get(v) {
+                None => diff.removed_vertices.push(*v),
+                Some(o) => {
+                    if vtx.data != o.data {
+                        diff.changed_data
+                            .push((*v, vtx.data.clone(), o.data.clone()));
+                    }
+                }
+            }
+        }
+        for (e, edge) in other.edges.iter().sorted_by_key(|(e, _)| **e) {
+            match self.edges.get(e) {
+                Some(s) if s.from == edge.from && s.to == edge.to && s.a == edge.a => {}
+                Some(_) => {
+                    diff.removed_edges.push(*e);
+                    diff.added_edges.push(*e);
+                }
+                None => diff.added_edges.push(*e),
+            }
+        }
+        for e in self.edges.keys().sorted() {
+            if !other.edges.contains_key(e) {
+                diff.removed_edges.push(*e);
+            }
+        }
+        diff.removed_edges.sort();
+        diff
+    }
+
+    /// Make a readable report of the `diff` with the `other` Universe,
+    /// one change per line: vertices added and removed, data changed,
+    /// then edges removed and added, each group ordered by IDs. Data
+    /// is printed with its type, like `int=42`, or as HEX, if the type
+    /// is not known.
+    pub fn diff_report(&self, other: &Universe) -> String {
+        let diff = self.diff(other);
+        let show = |d: &Option<Data>| match d {
+            Some(d) => render(d),
+            None => "nothing".to_string(),
+        };
+        let mut lines = vec![];
+        for v in diff.added_vertices.iter() {
+            lines.push(match &other.vertices[v].data {
+                Some(d) => format!("+ ν{} ({})", v, render(d)),
+                None => format!("+ ν{}", v),
+            });
+        }
+        for v in diff.removed_vertices.iter() {
+            lines.push(format!("- ν{}", v));
+        }
+        for (v, before, after) in diff.changed_data.iter() {
+            lines.push(format!("~ ν{} data {} -> {}", v, show(before), show(after)));
+        }
+        for e in diff.removed_edges.iter() {
+            let edge = &self.edges[e];
+            lines.push(format!(
+                "- edge {} (ν{} --{}--> ν{})",
+                e, edge.from, edge.a, edge.to
+            ));
+        }
+        for e in diff.added_edges.iter() {
+            let edge = &other.edges[e];
+            lines.push(format!(
+                "+ edge {} (ν{} --{}--> ν{})",
+                e, edge.from, edge.a, edge.to
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Print the data together with its type, falling back to HEX.
+fn render(d: &Data) -> String {
+    let typed = match d.kind() {
+        DataType::Int => d.as_int().map(|i| format!("int={}", i)).ok(),
+        DataType::Float => d.as_float().map(|f| format!("float={}", f)).ok(),
+        DataType::Bool => d.as_bool().map(|b| format!("bool={}", b)).ok(),
+        DataType::String => d.as_string().map(|s| format!("string={:?}", s)).ok(),
+        DataType::Unknown => None,
+    };
+    typed.unwrap_or_else(|| d.as_hex())
+}
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn reports_changes() -> Result<()> {
+    let mut before = Universe::empty();
+    before.add(0)?;
+    before.add(1)?;
+    before.add(2)?;
+    before.bind(101, 0, 1, "x")?;
+    before.bind(102, 0, 2, "y")?;
+    before.data(2, Data::from_hex("2a".to_string()))?;
+    let mut after = Universe::empty();
+    after.add(0)?;
+    after.add(2)?;
+    after.add(7)?;
+    after.bind(102, 0, 2, "y")?;
+    after.bind(103, 0, 7, "z")?;
+    after.data(2, Data::from_hex("2b".to_string()))?;
+    after.data(7, Data::from_int(99))?;
+    let report = before.diff_report(&after);
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!("+ ν7 (int=99)", lines[0]);
+    assert_eq!("- ν1", lines[1]);
+    assert_eq!("~ ν2 data 2a -> 2b", lines[2]);
+    assert!(lines.contains(&"- edge 101 (ν0 --x--> ν1)"));
+    assert!(lines.contains(&"+ edge 103 (ν0 --z--> ν7)"));
+    assert_eq!(report, before.diff_report(&after));
+    assert!(before.diff(&before).is_empty());
+    assert_eq!("", before.diff_report(&before));
+    let mut renamed = before.clone();
+    renamed.data(2, Data::from_str("hi"))?;
+    assert_eq!(
+        "~ ν2 data 2a -> string=\"hi\"",
+        before.diff_report(&renamed)
+    );
+    Ok(())
+}
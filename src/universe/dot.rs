@@ -0,0 +1,75 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use itertools::Itertools;
+
+impl Universe {
+    /// Render the Universe as a Graphviz `digraph`, which may be turned
+    /// into a picture by `dot -Tpng`. Vertices are labeled as `ν1`, with
+    /// their data in HEX, if present, while edges are labeled with their
+    /// attributes and IDs. Backward `ρ` and `𝜎` edges are dashed.
+    pub fn to_dot(&self) -> String {
+        let mut lines = vec!["digraph {".to_string()];
+        for (v, vtx) in self.vertices.iter().sorted_by_key(|(v, _)| **v) {
+            lines.push(match &vtx.data {
+                Some(d) => format!("  v{} [label=\"ν{}\\n{}\"];", v, v, d.as_hex()),
+                None => format!("  v{} [label=\"ν{}\"];", v, v),
+            });
+        }
+        for (i, e) in self.edges.iter().sorted_by_key(|(i, _)| **i) {
+            lines.push(format!(
+                "  v{} -> v{} [label=\"{} ε{}\"{}];",
+                e.from,
+                e.to,
+                e.a.replace('\\', "\\\\").replace('"', "\\\""),
+                i,
+                if e.backward() { ", style=dashed" } else { "" }
+            ));
+        }
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn renders_int_and_rand() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "int")?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, 0, v2, "rand")?;
+    let dot = uni.to_dot();
+    assert!(dot.starts_with("digraph {"));
+    assert!(dot.contains(format!("  v{} [label=\"ν{}\"];", v1, v1).as_str()));
+    assert!(dot.contains(format!("  v{} [label=\"ν{}\"];", v2, v2).as_str()));
+    assert!(dot.contains(format!("  v0 -> v{} [label=\"int ε{}\"];", v1, e1).as_str()));
+    assert!(dot.contains(format!("  v{} -> v0 [label=\"ρ ε", v2).as_str()));
+    assert!(dot.contains("style=dashed"));
+    Ok(())
+}
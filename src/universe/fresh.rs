@@ -0,0 +1,98 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::remap::relocate;
+use crate::universe::{Edge, Universe};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+impl Universe {
+    /// Make a deep copy of the Universe, where all vertices and edges
+    /// get new IDs, which are bigger than any ID of this Universe. Returns
+    /// the copy together with the mapping from old vertex IDs to new ones,
+    /// including the root, which is not `ν0` in the copy anymore.
+    pub fn clone_fresh(&self) -> (Universe, HashMap<u32, u32>) {
+        let mut next = self
+            .vertices
+            .keys()
+            .chain(self.edges.keys())
+            .max()
+            .map_or(0, |m| m + 1);
+        let mut mapping = HashMap::new();
+        for v in self.vertices.keys().sorted() {
+            mapping.insert(*v, next);
+            next += 1;
+        }
+        let mut uni = Universe::empty();
+        uni.atoms = self.atoms.clone();
+        uni.verbosity = self.verbosity;
+        uni.max_deref = self.max_deref;
+        uni.auto_repair = self.auto_repair;
+        uni.warn_dead_ends = self.warn_dead_ends;
+        for (v, vtx) in self.vertices.iter() {
+            let mut copy = vtx.clone();
+            copy.search = relocate(vtx.search.as_str(), &mapping);
+            uni.vertices.insert(mapping[v], copy);
+        }
+        for (_, e) in self.edges.iter().sorted_by_key(|(i, _)| **i) {
+            uni.edges.insert(
+                next,
+                Edge::new(
+                    *mapping.get(&e.from).unwrap_or(&e.from),
+                    *mapping.get(&e.to).unwrap_or(&e.to),
+                    e.a.clone(),
+                ),
+            );
+            next += 1;
+        }
+        uni.tick = next;
+        (uni, mapping)
+    }
+}
+
+#[cfg(test)]
+use crate::data::Data;
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn clones_with_fresh_ids() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "x")?;
+    uni.data(v1, Data::from_int(42))?;
+    let (mut copy, mapping) = uni.clone_fresh();
+    assert_eq!(2, mapping.len());
+    assert!(mapping.values().all(|v| *v > e1));
+    assert!(copy.inconsistencies().is_empty());
+    assert_eq!(mapping[&v1], copy.find(mapping[&0], "x")?);
+    assert_eq!(
+        42,
+        copy.dataize(format!("ν{}.x", mapping[&0]).as_str())?
+            .as_int()?
+    );
+    let v2 = copy.next_id();
+    assert!(!mapping.values().any(|v| *v == v2));
+    Ok(())
+}
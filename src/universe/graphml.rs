@@ -0,0 +1,167 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::data::Data;
+use crate::universe::{Edge, Universe};
+use anyhow::{anyhow, Context, Result};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::str::FromStr;
+
+impl Universe {
+    /// Render the Universe as a GraphML document, which may be opened
+    /// in yEd, Gephi, or Cytoscape. Vertices become nodes `v0`, `v1`, etc.,
+    /// with their data in HEX, while edges become edges `e2`, `e3`, etc.,
+    /// with their labels. Atoms are not rendered, since lambdas can't be printed.
+    pub fn to_graphml(&self) -> String {
+        let mut lines = vec![
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>".to_string(),
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">".to_string(),
+            "  <key id=\"data\" for=\"node\" attr.name=\"data\" attr.type=\"string\"/>".to_string(),
+            "  <key id=\"label\" for=\"edge\" attr.name=\"label\" attr.type=\"string\"/>"
+                .to_string(),
+            "  <graph id=\"G\" edgedefault=\"directed\">".to_string(),
+        ];
+        for (v, vtx) in self.vertices.iter().sorted_by_key(|(v, _)| **v) {
+            lines.push(match &vtx.data {
+                Some(d) => format!(
+                    "    <node id=\"v{}\"><data key=\"data\">{}</data></node>",
+                    v,
+                    d.as_hex().replace('-', "")
+                ),
+                None => format!("    <node id=\"v{}\"/>", v),
+            });
+        }
+        for (i, e) in self.edges.iter().sorted_by_key(|(i, _)| **i) {
+            lines.push(format!(
+                "    <edge id=\"e{}\" source=\"v{}\" target=\"v{}\"><data key=\"label\">{}</data></edge>",
+                i,
+                e.from,
+                e.to,
+                escape(&e.a)
+            ));
+        }
+        lines.push("  </graph>".to_string());
+        lines.push("</graphml>".to_string());
+        lines.join("\n")
+    }
+
+    /// Make a new Universe from a GraphML document, previously rendered
+    /// by `to_graphml`. Vertex and edge IDs are preserved.
+    pub fn from_graphml(xml: &str) -> Result<Universe> {
+        lazy_static! {
+            static ref NODE: Regex =
+                Regex::new("(?s)<node id=\"v([0-9]+)\" *(?:/>|>(.*?)</node>)").unwrap();
+            static ref EDGE: Regex = Regex::new(
+                "(?s)<edge id=\"e([0-9]+)\" source=\"v([0-9]+)\" target=\"v([0-9]+)\" *(?:/>|>(.*?)</edge>)"
+            )
+            .unwrap();
+            static ref DATA: Regex = Regex::new("<data key=\"data\">([^<]*)</data>").unwrap();
+            static ref LABEL: Regex = Regex::new("<data key=\"label\">([^<]*)</data>").unwrap();
+        }
+        let mut uni = Universe::empty();
+        for cap in NODE.captures_iter(xml) {
+            let v = u32::from_str(&cap[1])?;
+            uni.add(v)?;
+            if let Some(d) = cap.get(2).and_then(|body| DATA.captures(body.as_str())) {
+                let hex = d[1].trim();
+                let bytes = hex::decode(hex).context(format!("Wrong data in v{}: '{}'", v, hex))?;
+                uni.data(v, Data::from_bytes(bytes))?;
+            }
+        }
+        for cap in EDGE.captures_iter(xml) {
+            let e = u32::from_str(&cap[1])?;
+            let from = u32::from_str(&cap[2])?;
+            let to = u32::from_str(&cap[3])?;
+            let a = cap
+                .get(4)
+                .and_then(|body| LABEL.captures(body.as_str()))
+                .context(format!("There is no label in e{}", e))?[1]
+                .to_string();
+            for v in [from, to] {
+                if !uni.vertices.contains_key(&v) {
+                    return Err(anyhow!("Edge e{} refers to unknown node v{}", e, v));
+                }
+            }
+            if uni.edges.contains_key(&e) {
+                return Err(anyhow!("Edge e{} is duplicated", e));
+            }
+            uni.edges.insert(e, Edge::new(from, to, unescape(&a)));
+        }
+        Ok(uni)
+    }
+}
+
+/// Escape XML special characters.
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Replace XML entities with the characters they stand for.
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[test]
+fn prints_simple_graphml() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(2, 0, 1, "<x>")?;
+    uni.data(1, Data::from_int(42))?;
+    let xml = uni.to_graphml();
+    assert!(xml.contains("<node id=\"v0\"/>"));
+    assert!(xml.contains("<node id=\"v1\"><data key=\"data\">000000000000002a</data></node>"));
+    assert!(xml.contains(
+        "<edge id=\"e2\" source=\"v0\" target=\"v1\"><data key=\"label\">&lt;x&gt;</data></edge>"
+    ));
+    Ok(())
+}
+
+#[test]
+fn parses_printed_graphml() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "a&b")?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, v1, v2, "\"привет\"")?;
+    uni.data(v2, Data::from_int(42))?;
+    let xml = uni.to_graphml();
+    let mut copy = Universe::from_graphml(xml.as_str())?;
+    assert!(copy.inconsistencies().is_empty());
+    assert_eq!(xml, copy.to_graphml());
+    assert!(uni.structurally_eq(&copy));
+    assert_eq!(42, copy.dataize("Φ.a&b.\"привет\"")?.as_int()?);
+    Ok(())
+}
@@ -29,7 +29,10 @@ impl Universe {
             return Err(anyhow!("Vertex ν{} already exists", v1));
         }
         self.vertices.insert(v1, Vertex::empty());
-        trace!("#add(ν{}): new vertex added", v1);
+        self.touch();
+        if self.verbosity.mutations() {
+            trace!("#add(ν{}): new vertex added", v1);
+        }
         Ok(())
     }
 }
@@ -49,26 +49,58 @@ impl Universe {
         if m.starts_with("S/") {
             vtx.lambda = Some(search);
             vtx.search = m.chars().skip(2).collect();
-            trace!(
-                "#atom(ν{}, '{}'): lambda SEARCH set to '{}'",
-                v1,
-                m,
-                vtx.search
-            );
+            if self.verbosity.mutations() {
+                trace!(
+                    "#atom(ν{}, '{}'): lambda SEARCH set to '{}'",
+                    v1,
+                    m,
+                    vtx.search
+                );
+            }
         } else {
             vtx.lambda = Some(match self.atoms.get(m) {
                 Some(a) => {
-                    trace!("#atom(ν{}, '{}'): lambda found and set", v1, m);
+                    if self.verbosity.mutations() {
+                        trace!("#atom(ν{}, '{}'): lambda found and set", v1, m);
+                    }
                     *a
                 }
                 None => {
-                    trace!("#atom(ν{}, '{}'): lambda NOT found but set to NIY", v1, m);
+                    if self.verbosity.mutations() {
+                        trace!("#atom(ν{}, '{}'): lambda NOT found but set to NIY", v1, m);
+                    }
                     not_implemented_yet
                 }
             });
         }
+        self.touch();
         Ok(())
     }
+
+    /// Set atom lambda, registered under the `name`, to the vertex `v1`,
+    /// just like `atom` does. If the name is not registered, an error
+    /// is returned, instead of setting the atom that is not implemented.
+    pub fn atom_named(&mut self, v1: u32, name: &str) -> Result<()> {
+        if !name.starts_with("S/") && !self.atoms.contains_key(name) {
+            return Err(anyhow!("Atom '{}' is not registered", name));
+        }
+        self.atom(v1, name)
+    }
+
+    /// Make a new vertex, bind it to `parent` with the label `a`,
+    /// and set atom `m` to it, just like `atom` does. Returns the ID
+    /// of the new vertex.
+    pub fn bind_atom(&mut self, parent: u32, a: &str, m: &str) -> Result<u32> {
+        if !self.vertices.contains_key(&parent) {
+            return Err(anyhow!("Can't find ν{}", parent));
+        }
+        let v = self.next_id();
+        self.add(v)?;
+        let e = self.next_id();
+        self.bind(e, parent, v, a)?;
+        self.atom(v, m)?;
+        Ok(v)
+    }
 }
 
 #[cfg(test)]
@@ -88,3 +120,94 @@ fn evaluates_dummy_atom() -> Result<()> {
     assert_eq!(0, uni.find(v1, "Δ")?);
     Ok(())
 }
+
+#[test]
+fn binds_atom_vertex() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.register("dummy", dummy);
+    uni.add(0)?;
+    let v1 = uni.bind_atom(0, "x", "dummy")?;
+    assert!(uni.inconsistencies().is_empty());
+    assert_eq!(v1, uni.find(0, "x")?);
+    assert_eq!(0, uni.find(0, "x.Δ")?);
+    assert!(uni.bind_atom(42, "y", "dummy").is_err());
+    Ok(())
+}
+
+#[cfg(test)]
+use crate::data::Data;
+
+#[cfg(test)]
+fn plus(uni: &mut Universe, v: u32) -> Result<u32> {
+    let mut sum = 0;
+    for d in uni.dataize_all(v, &["α0", "α1"]) {
+        sum += d?.as_int()?;
+    }
+    let r = uni.next_id();
+    uni.add(r)?;
+    uni.data(r, Data::from_int(sum))?;
+    Ok(r)
+}
+
+#[test]
+fn passes_own_vertex_to_atom() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.register("plus", plus);
+    uni.add(0)?;
+    for (a, x, y) in [("a", 1, 2), ("b", 40, 2)] {
+        let v = uni.bind_atom(0, a, "plus")?;
+        for (k, n) in [("α0", x), ("α1", y)] {
+            let arg = uni.next_id();
+            uni.add(arg)?;
+            let e = uni.next_id();
+            uni.bind(e, v, arg, k)?;
+            uni.data(arg, Data::from_int(n))?;
+        }
+    }
+    assert_eq!(3, uni.dataize("Φ.a")?.as_int()?);
+    assert_eq!(42, uni.dataize("Φ.b")?.as_int()?);
+    Ok(())
+}
+
+#[test]
+fn sets_atom_by_name() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.register("rand", super::rand);
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(10, 0, 1, "int")?;
+    uni.add(2)?;
+    uni.bind(20, 0, 2, "x")?;
+    uni.atom_named(2, "rand")?;
+    assert!(uni.dataize("Φ.x.Δ").is_ok());
+    let err = uni.atom_named(2, "unknown").unwrap_err();
+    assert_eq!("Atom 'unknown' is not registered", err.to_string());
+    Ok(())
+}
+
+#[cfg(test)]
+use crate::gmi::Gmi;
+
+#[test]
+fn deploys_only_registered_atoms() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.register("rand", super::rand);
+    let total = Gmi::from_string(
+        "
+        ADD(0);
+        ADD(1);
+        BIND(10, 0, 1, 'int');
+        ADD(2);
+        BIND(20, 0, 2, 'x');
+        ATOM(2, 'rand');
+        "
+        .to_string(),
+    )?
+    .deploy_to(&mut uni)?;
+    assert_eq!(6, total);
+    let first = uni.dataize("Φ.x.Δ")?.as_int()?;
+    let second = uni.dataize("Φ.x.Δ")?.as_int()?;
+    assert_ne!(first, second);
+    assert!(Universe::from_script("ADD(0);\nATOM(0, 'rand');").is_err());
+    Ok(())
+}
@@ -45,6 +45,7 @@ impl Universe {
             ));
         }
         self.edges.insert(e1, Edge::new(v1, v2, a.to_string()));
+        self.touch();
         if a != "ρ" && a != "𝜎" {
             if self.edge(v2, "ρ").is_none() {
                 let e2 = self.next_id();
@@ -55,17 +56,19 @@ impl Universe {
                 self.bind(e3, v2, v1, "𝜎")?;
             }
         }
-        trace!(
-            "#bind(ε{}, ν{}, ν{}, '{}'): edge added ν{}-ε{}({})>ν{}",
-            e1,
-            v1,
-            v2,
-            a,
-            v1,
-            e1,
-            a,
-            v2
-        );
+        if self.verbosity.mutations() {
+            trace!(
+                "#bind(ε{}, ν{}, ν{}, '{}'): edge added ν{}-ε{}({})>ν{}",
+                e1,
+                v1,
+                v2,
+                a,
+                v1,
+                e1,
+                a,
+                v2
+            );
+        }
         Ok(())
     }
 }
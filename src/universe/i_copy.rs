@@ -56,20 +56,23 @@ impl Universe {
             .lambda = None;
         let e3 = self.next_id();
         self.edges.insert(e3, Edge::new(v3, v2, "π".to_string()));
-        trace!(
-            "#copy(ε{}, ν{}, ε{}): ν{}-ε{}>ν{} restructured as ν{}-ε{}>ν{}-ε{}(π)>ν{}",
-            e1,
-            v3,
-            e2,
-            v1,
-            e1,
-            v2,
-            v1,
-            e2,
-            v3,
-            e3,
-            v2
-        );
+        self.touch();
+        if self.verbosity.mutations() {
+            trace!(
+                "#copy(ε{}, ν{}, ε{}): ν{}-ε{}>ν{} restructured as ν{}-ε{}>ν{}-ε{}(π)>ν{}",
+                e1,
+                v3,
+                e2,
+                v1,
+                e1,
+                v2,
+                v1,
+                e2,
+                v3,
+                e3,
+                v2
+            );
+        }
         Ok(())
     }
 }
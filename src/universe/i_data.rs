@@ -30,7 +30,10 @@ impl Universe {
             .get_mut(&v)
             .context(format!("Can't find ν{}", v))?
             .data = Some(d.clone());
-        trace!("#data(ν{}, '{}'): data set", v, d.as_hex());
+        self.touch();
+        if self.verbosity.mutations() {
+            trace!("#data(ν{}, '{}'): data set", v, d.as_hex());
+        }
         Ok(())
     }
 }
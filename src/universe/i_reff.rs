@@ -0,0 +1,78 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use anyhow::{anyhow, Context, Result};
+use log::trace;
+
+impl Universe {
+    /// Finds a vertex by the locator `loc`, starting from `v1`, and makes
+    /// an edge `e1` from `v1` to it, labeled as `a`, just like `bind` does.
+    /// If the vertex can't be found or is absent, an error is returned.
+    pub fn reff(&mut self, e1: u32, v1: u32, loc: &str, a: &str) -> Result<()> {
+        let v2 = self
+            .find(v1, loc)
+            .and_then(|v| {
+                if self.vertices.contains_key(&v) {
+                    Ok(v)
+                } else {
+                    Err(anyhow!("ν{} is absent", v))
+                }
+            })
+            .context(format!(
+                "reff(ε{}, ν{}, \"{}\"): can't resolve locator",
+                e1, v1, loc
+            ))?;
+        self.bind(e1, v1, v2, a)?;
+        if self.verbosity.mutations() {
+            trace!(
+                "#reff(ε{}, ν{}, '{}', '{}'): edge added ν{}-ε{}({})>ν{}",
+                e1,
+                v1,
+                loc,
+                a,
+                v1,
+                e1,
+                a,
+                v2
+            );
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn refers_to_found_vertex() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(10, 0, 1, "x")?;
+    uni.add(2)?;
+    uni.bind(20, 1, 2, "y")?;
+    uni.reff(30, 0, "x.y", "z")?;
+    assert_eq!(2, uni.find(0, "z")?);
+    let err = uni.reff(40, 0, "ν9", "q").unwrap_err();
+    assert_eq!(
+        "reff(ε40, ν0, \"ν9\"): can't resolve locator",
+        err.to_string()
+    );
+    assert!(uni.reff(41, 0, "x.w", "q").is_err());
+    Ok(())
+}
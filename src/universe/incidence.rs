@@ -0,0 +1,76 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use itertools::Itertools;
+
+impl Universe {
+    /// Get all vertices, ordered by IDs, each together with the list
+    /// of its outgoing edges, as pairs of target vertices and labels,
+    /// ordered by edge IDs. Vertices without outgoing edges are listed
+    /// with empty lists.
+    pub fn to_incidence_list(&self) -> Vec<(u32, Vec<(u32, String)>)> {
+        self.vertices
+            .keys()
+            .sorted()
+            .map(|v| {
+                (
+                    *v,
+                    self.edges
+                        .iter()
+                        .filter(|(_, e)| e.from == *v)
+                        .sorted_by_key(|(i, _)| **i)
+                        .map(|(_, e)| (e.to, e.a.clone()))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn lists_incidences() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.add(2)?;
+    uni.bind(10, 0, 1, "x")?;
+    uni.bind(11, 0, 2, "y")?;
+    uni.bind(12, 1, 2, "z")?;
+    let list = uni.to_incidence_list();
+    assert_eq!(3, list.len());
+    assert_eq!(
+        (0, vec![(1, "x".to_string()), (2, "y".to_string())]),
+        list[0]
+    );
+    assert_eq!(1, list[1].0);
+    assert_eq!(
+        vec!["ρ", "𝜎", "z"],
+        list[1]
+            .1
+            .iter()
+            .map(|(_, a)| a.as_str())
+            .collect::<Vec<&str>>()
+    );
+    Ok(())
+}
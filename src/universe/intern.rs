@@ -0,0 +1,118 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::data::DataType;
+use crate::universe::Universe;
+use itertools::Itertools;
+use log::trace;
+use std::collections::HashMap;
+
+impl Universe {
+    /// Find all data vertices with identical data of the same type and
+    /// keep only one of each kind, the one with the smallest ID. All
+    /// edges arriving to the duplicates are redirected to it, while the
+    /// duplicates are deleted, together with their `ρ` and `𝜎` edges.
+    /// Only vertices with data, without atoms and without outgoing edges
+    /// (except `ρ` and `𝜎`) are taken into account. Returns the mapping
+    /// from the IDs of deleted vertices to the IDs of the kept ones.
+    pub fn intern_all_data(&mut self) -> HashMap<u32, u32> {
+        let mut kept: HashMap<(DataType, String), u32> = HashMap::new();
+        let mut mapping = HashMap::new();
+        for (v, vtx) in self.vertices.iter().sorted_by_key(|(v, _)| **v) {
+            if vtx.lambda.is_some() || self.edges.values().any(|e| e.from == *v && !e.backward()) {
+                continue;
+            }
+            if let Some(d) = &vtx.data {
+                let rep = *kept.entry((d.kind(), d.as_hex())).or_insert(*v);
+                if rep != *v {
+                    mapping.insert(*v, rep);
+                }
+            }
+        }
+        self.edges.retain(|_, e| !mapping.contains_key(&e.from));
+        for e in self.edges.values_mut() {
+            if let Some(rep) = mapping.get(&e.to) {
+                e.to = *rep;
+            }
+        }
+        for (v, rep) in mapping.iter() {
+            self.vertices.remove(v);
+            if self.verbosity.mutations() {
+                trace!("#intern_all_data(): ν{} replaced with ν{}", v, rep);
+            }
+        }
+        if !mapping.is_empty() {
+            self.touch();
+        }
+        mapping
+    }
+}
+
+#[cfg(test)]
+use crate::data::Data;
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn interns_identical_data() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let mut kids = vec![];
+    for (a, d) in [("x", 42), ("y", 42), ("z", 7)] {
+        let v = uni.next_id();
+        uni.add(v)?;
+        let e = uni.next_id();
+        uni.bind(e, 0, v, a)?;
+        uni.data(v, Data::from_int(d))?;
+        kids.push(v);
+    }
+    let mapping = uni.intern_all_data();
+    assert_eq!(HashMap::from([(kids[1], kids[0])]), mapping);
+    assert!(uni.inconsistencies().is_empty());
+    assert_eq!(kids[0], uni.find(0, "y")?);
+    assert_eq!(42, uni.dataize("Φ.y")?.as_int()?);
+    assert_eq!(7, uni.dataize("Φ.z")?.as_int()?);
+    assert!(uni.intern_all_data().is_empty());
+    Ok(())
+}
+
+#[test]
+fn keeps_data_of_different_types() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    for (a, d) in [
+        ("x", Data::from_bool(true)),
+        ("y", Data::from_hex("01".to_string())),
+        ("z", Data::from_bool(true)),
+    ] {
+        let v = uni.next_id();
+        uni.add(v)?;
+        let e = uni.next_id();
+        uni.bind(e, 0, v, a)?;
+        uni.data(v, d)?;
+    }
+    let x = uni.find(0, "x")?;
+    let z = uni.find(0, "z")?;
+    assert_eq!(HashMap::from([(z, x)]), uni.intern_all_data());
+    assert_eq!(Some(&vec![x]), uni.group_by_type().get(&DataType::Bool));
+    assert_eq!(1, uni.group_by_type()[&DataType::Unknown].len());
+    Ok(())
+}
@@ -0,0 +1,72 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use itertools::Itertools;
+
+impl Universe {
+    /// Get all edges departing from `v`, as tuples of edge ID, label,
+    /// and the vertex the edge arrives to, ordered by edge IDs. The
+    /// `ρ` and `𝜎` edges are included too.
+    pub fn kids(&self, v: u32) -> Vec<(u32, String, u32)> {
+        self.edges
+            .iter()
+            .filter(|(_, e)| e.from == v)
+            .map(|(i, e)| (*i, e.a.clone(), e.to))
+            .sorted()
+            .collect()
+    }
+
+    /// Get all edges arriving to `v`, as tuples of edge ID, label,
+    /// and the vertex the edge departs from, ordered by edge IDs.
+    pub fn parents(&self, v: u32) -> Vec<(u32, String, u32)> {
+        self.edges
+            .iter()
+            .filter(|(_, e)| e.to == v)
+            .map(|(i, e)| (*i, e.a.clone(), e.from))
+            .sorted()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn lists_kids_and_parents() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(10, 0, 1, "x")?;
+    assert_eq!(vec![(10, "x".to_string(), 1)], uni.kids(0));
+    let kids = uni.kids(1);
+    assert_eq!(2, kids.len());
+    assert_eq!(
+        vec!["ρ", "𝜎"],
+        kids.iter()
+            .map(|(_, a, _)| a.as_str())
+            .sorted()
+            .collect::<Vec<&str>>()
+    );
+    assert!(kids.iter().all(|(_, _, to)| *to == 0));
+    assert_eq!(vec![(10, "x".to_string(), 0)], uni.parents(1));
+    assert_eq!(2, uni.parents(0).len());
+    Ok(())
+}
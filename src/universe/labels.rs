@@ -0,0 +1,115 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use itertools::Itertools;
+
+impl Universe {
+    /// Find all edges with labels, which are not `allowed` by the
+    /// predicate. Returns their IDs together with the labels, ordered
+    /// by edge IDs. Nothing is changed in the Universe.
+    pub fn validate_labels(&self, allowed: impl Fn(&str) -> bool) -> Vec<(u32, String)> {
+        self.edges
+            .iter()
+            .filter(|(_, e)| !allowed(e.a.as_str()))
+            .map(|(i, e)| (*i, e.a.clone()))
+            .sorted_by_key(|(i, _)| *i)
+            .collect()
+    }
+
+    /// Find all edges labeled as `a`. Returns their IDs together
+    /// with the vertices they depart from and arrive to, ordered
+    /// by edge IDs.
+    pub fn edges_with_label(&self, a: &str) -> Vec<(u32, u32, u32)> {
+        self.edges
+            .iter()
+            .filter(|(_, e)| e.a == a)
+            .map(|(i, e)| (*i, e.from, e.to))
+            .sorted_by_key(|(i, _, _)| *i)
+            .collect()
+    }
+
+    /// Find all edges departing from `v` with labels matching the
+    /// `pattern`. Returns their IDs together with the vertices they
+    /// arrive to, ordered by edge IDs. This is a query helper for
+    /// tools, it is not used by `find` when locators are resolved.
+    /// It is available only with the `label-patterns` feature.
+    #[cfg(feature = "label-patterns")]
+    pub fn find_by_label_pattern(&self, v: u32, pattern: &regex::Regex) -> Vec<(u32, u32)> {
+        self.edges
+            .iter()
+            .filter(|(_, e)| e.from == v && pattern.is_match(e.a.as_str()))
+            .map(|(i, e)| (*i, e.to))
+            .sorted_by_key(|(i, _)| *i)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn finds_wrong_labels() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(10, 0, 1, "x")?;
+    uni.bind(11, 0, 1, "hello world")?;
+    uni.bind(12, 0, 1, "42")?;
+    let re = regex::Regex::new("^([a-z][a-z0-9_-]*|ρ|𝜎)$")?;
+    assert_eq!(
+        vec![(11, "hello world".to_string()), (12, "42".to_string())],
+        uni.validate_labels(|a| re.is_match(a))
+    );
+    Ok(())
+}
+
+#[test]
+fn finds_edges_by_label() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.add(2)?;
+    uni.bind(10, 0, 1, "x")?;
+    uni.bind(11, 1, 2, "x")?;
+    uni.bind(12, 0, 2, "y")?;
+    assert_eq!(vec![(10, 0, 1), (11, 1, 2)], uni.edges_with_label("x"));
+    assert_eq!(2, uni.edges_with_label("ρ").len());
+    assert!(uni.edges_with_label("z").is_empty());
+    Ok(())
+}
+
+#[cfg(feature = "label-patterns")]
+#[test]
+fn finds_edges_by_pattern() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.add(2)?;
+    uni.bind(10, 0, 1, "get_x")?;
+    uni.bind(11, 0, 2, "get_y")?;
+    uni.bind(12, 0, 2, "set_y")?;
+    uni.bind(13, 1, 2, "get_z")?;
+    assert_eq!(
+        vec![(10, 1), (11, 2)],
+        uni.find_by_label_pattern(0, &regex::Regex::new("^get_")?)
+    );
+    Ok(())
+}
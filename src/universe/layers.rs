@@ -0,0 +1,74 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+impl Universe {
+    /// Group all vertices, which are reachable from `root`, by their
+    /// distance from it, following edges breadth-first, except `ρ`
+    /// and `𝜎` ones. The first layer contains only the `root`, each
+    /// layer is ordered by IDs. If `root` is absent, nothing is returned.
+    pub fn layers(&self, root: u32) -> Vec<Vec<u32>> {
+        let mut layers = vec![];
+        if !self.vertices.contains_key(&root) {
+            return layers;
+        }
+        let mut seen = HashSet::from([root]);
+        let mut layer = vec![root];
+        while !layer.is_empty() {
+            let next: Vec<u32> = self
+                .edges
+                .values()
+                .filter(|e| !e.backward() && layer.contains(&e.from))
+                .map(|e| e.to)
+                .filter(|v| self.vertices.contains_key(v) && seen.insert(*v))
+                .sorted()
+                .collect();
+            layers.push(layer);
+            layer = next;
+        }
+        layers
+    }
+}
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn groups_balanced_tree() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    for v in [1, 2] {
+        uni.add(v)?;
+        uni.bind(100 + v, 0, v, format!("a{}", v).as_str())?;
+        for k in [1, 2] {
+            let v2 = v * 2 + k;
+            uni.add(v2)?;
+            uni.bind(100 + v2, v, v2, format!("b{}", k).as_str())?;
+        }
+    }
+    uni.add(7)?;
+    assert_eq!(vec![vec![0], vec![1, 2], vec![3, 4, 5, 6]], uni.layers(0));
+    assert_eq!(vec![vec![2], vec![5, 6]], uni.layers(2));
+    assert!(uni.layers(42).is_empty());
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use itertools::Itertools;
+
+impl Universe {
+    /// Get all edges, which were traversed by `find` and `dataize`,
+    /// together with the number of traversals, the most traversed
+    /// edges go first.
+    pub fn hot_edges(&self) -> Vec<(u32, usize)> {
+        self.hits
+            .iter()
+            .filter(|(e, _)| self.edges.contains_key(e))
+            .map(|(e, n)| (*e, *n))
+            .sorted_by(|(e1, n1), (e2, n2)| n2.cmp(n1).then(e1.cmp(e2)))
+            .collect()
+    }
+
+    /// Forget all collected metrics.
+    pub fn reset_metrics(&mut self) {
+        self.hits.clear();
+    }
+}
+
+#[cfg(test)]
+use crate::data::Data;
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn counts_hot_edges() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "x")?;
+    uni.data(v1, Data::from_int(1))?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, 0, v2, "y")?;
+    uni.data(v2, Data::from_int(2))?;
+    for _ in 0..3 {
+        uni.dataize("Φ.x")?;
+    }
+    uni.dataize("Φ.y")?;
+    assert_eq!(vec![(e1, 3), (e2, 1)], uni.hot_edges());
+    uni.reset_metrics();
+    assert!(uni.hot_edges().is_empty());
+    Ok(())
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use itertools::Itertools;
+
+impl Universe {
+    /// Get IDs of all vertices, which are real objects, ordered. A vertex
+    /// is an object if it has data or at least one outgoing edge, which
+    /// is not `ρ` or `𝜎`. If `atoms` is `true`, vertices with atoms are
+    /// objects too, even if they have neither data nor such edges.
+    pub fn objects(&self, atoms: bool) -> Vec<u32> {
+        self.vertices
+            .iter()
+            .filter(|(v, vtx)| {
+                vtx.data.is_some()
+                    || (atoms && vtx.lambda.is_some())
+                    || self.edges.values().any(|e| e.from == **v && !e.backward())
+            })
+            .map(|(v, _)| *v)
+            .sorted()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+use crate::data::Data;
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn finds_real_objects() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "x")?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, 0, v2, "y")?;
+    uni.data(v2, Data::from_int(42))?;
+    let v3 = uni.next_id();
+    uni.add(v3)?;
+    let e3 = uni.next_id();
+    uni.bind(e3, 0, v3, "z")?;
+    uni.atom(v3, "S/Φ.y")?;
+    assert_eq!(vec![0, v2], uni.objects(false));
+    assert_eq!(vec![0, v2, v3], uni.objects(true));
+    Ok(())
+}
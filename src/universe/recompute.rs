@@ -0,0 +1,95 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use anyhow::{Context, Result};
+use log::trace;
+
+impl Universe {
+    /// Fire every atom once, children before parents (in the reverse of
+    /// `topo_order`), and put the data of its result into the atom vertex,
+    /// removing the atom. Returns the number of atoms recomputed. Atoms
+    /// with side effects (mutating the Universe or not returning the same
+    /// data every time) may give different results, depending on the order.
+    pub fn recompute_atoms(&mut self) -> Result<usize> {
+        let mut total = 0;
+        for v in self.topo_order()?.into_iter().rev() {
+            match self.vertices.get(&v) {
+                Some(vtx) if vtx.lambda.is_some() => {}
+                _ => continue,
+            }
+            let r = self.fire(v)?;
+            let d = self.find(r, "Δ")?;
+            let data = self
+                .vertices
+                .get(&d)
+                .context(format!("Can't find ν{}", d))?
+                .data
+                .clone()
+                .context(format!("There is no data in ν{}", d))?;
+            let vtx = self
+                .vertices
+                .get_mut(&v)
+                .context(format!("Can't find ν{}", v))?;
+            vtx.data = Some(data);
+            vtx.lambda = None;
+            vtx.search = String::new();
+            self.touch();
+            if self.verbosity.mutations() {
+                trace!("#recompute_atoms(): ν{} materialized from ν{}", v, d);
+            }
+            total += 1;
+        }
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+use crate::data::Data;
+
+#[cfg(test)]
+fn inner(uni: &mut Universe, v: u32) -> Result<u32> {
+    uni.find(v, "k")
+}
+
+#[cfg(test)]
+fn outer(uni: &mut Universe, v: u32) -> Result<u32> {
+    uni.find(v, "x.Δ")
+}
+
+#[test]
+fn materializes_nested_atoms() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.register("inner", inner);
+    uni.register("outer", outer);
+    uni.add(0)?;
+    let v1 = uni.bind_atom(0, "a", "outer")?;
+    let v2 = uni.bind_atom(v1, "x", "inner")?;
+    let v3 = uni.next_id();
+    uni.add(v3)?;
+    let e = uni.next_id();
+    uni.bind(e, v2, v3, "k")?;
+    uni.data(v3, Data::from_int(42))?;
+    assert_eq!(2, uni.recompute_atoms()?);
+    assert_eq!(42, uni.dataize("Φ.a")?.as_int()?);
+    assert_eq!(42, uni.dataize("Φ.a.x")?.as_int()?);
+    assert_eq!(0, uni.recompute_atoms()?);
+    Ok(())
+}
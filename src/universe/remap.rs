@@ -0,0 +1,114 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use anyhow::{anyhow, Context, Result};
+use itertools::Itertools;
+use log::trace;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+impl Universe {
+    /// Change IDs of all vertices according to the `mapping` from old
+    /// IDs to new ones, rewriting the edges and the `ν`-prefixed
+    /// locators of search atoms. Every vertex must be in the mapping
+    /// and no two vertices may get the same new ID. Edge IDs stay intact,
+    /// that's why a new ID may not be equal to the ID of any edge.
+    pub fn remap_ids(&mut self, mapping: &HashMap<u32, u32>) -> Result<()> {
+        let mut seen = HashMap::new();
+        for v in self.vertices.keys().sorted() {
+            let n = mapping.get(v).context(format!("ν{} is not mapped", v))?;
+            if let Some(o) = seen.insert(*n, *v) {
+                return Err(anyhow!("Both ν{} and ν{} are mapped to ν{}", o, v, n));
+            }
+            if self.edges.contains_key(n) {
+                return Err(anyhow!("ν{} can't be mapped to ν{}, ε{} exists", v, n, n));
+            }
+        }
+        for (i, e) in self.edges.iter() {
+            for v in [e.from, e.to] {
+                if !mapping.contains_key(&v) {
+                    return Err(anyhow!("Edge ε{} refers to ν{}, which is not mapped", i, v));
+                }
+            }
+        }
+        self.vertices = self
+            .vertices
+            .drain()
+            .map(|(v, mut vtx)| {
+                vtx.search = relocate(vtx.search.as_str(), mapping);
+                (mapping[&v], vtx)
+            })
+            .collect();
+        for e in self.edges.values_mut() {
+            e.from = mapping[&e.from];
+            e.to = mapping[&e.to];
+        }
+        if self.verbosity.mutations() {
+            trace!("#remap_ids(): {} vertices remapped", mapping.len());
+        }
+        self.touch();
+        Ok(())
+    }
+}
+
+/// Change all `ν`-prefixed sectors of the locator according
+/// to the `mapping`, leaving all other sectors intact.
+pub(super) fn relocate(loc: &str, mapping: &HashMap<u32, u32>) -> String {
+    loc.split('.')
+        .map(|k| match k.strip_prefix('ν').map(u32::from_str) {
+            Some(Ok(id)) if mapping.contains_key(&id) => format!("ν{}", mapping[&id]),
+            _ => k.to_string(),
+        })
+        .join(".")
+}
+
+#[test]
+fn remaps_vertices() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(20, 0, 1, "x")?;
+    uni.add(3)?;
+    uni.bind(21, 0, 3, "y")?;
+    uni.atom(3, "S/ν1")?;
+    uni.remap_ids(&HashMap::from([(0, 0), (1, 10), (3, 30)]))?;
+    assert!(uni.inconsistencies().is_empty());
+    assert_eq!(10, uni.find(0, "x")?);
+    assert_eq!(0, uni.find(10, "ρ")?);
+    assert_eq!(10, uni.find(0, "y.Δ")?);
+    Ok(())
+}
+
+#[test]
+fn rejects_wrong_mapping() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(20, 0, 1, "x")?;
+    assert!(uni.remap_ids(&HashMap::from([(0, 0)])).is_err());
+    assert!(uni.remap_ids(&HashMap::from([(0, 5), (1, 5)])).is_err());
+    let err = uni
+        .remap_ids(&HashMap::from([(0, 0), (1, 20)]))
+        .unwrap_err();
+    assert_eq!("ν1 can't be mapped to ν20, ε20 exists", err.to_string());
+    assert_eq!(1, uni.find(0, "x")?);
+    Ok(())
+}
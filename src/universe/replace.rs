@@ -0,0 +1,123 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use log::trace;
+use std::collections::HashSet;
+
+impl Universe {
+    /// Redirect all edges arriving to `target` (except `ρ` and `𝜎` ones)
+    /// to `replacement` and delete all vertices of the `target` subtree,
+    /// which are not reachable anymore, neither from `ν0` nor from
+    /// `replacement`. If `replacement` ends up without a `ρ` edge,
+    /// it gets `ρ` and `𝜎` edges to the first of the redirected parents.
+    pub fn replace_subtree(&mut self, target: u32, replacement: u32) -> Result<()> {
+        for v in [target, replacement] {
+            if !self.vertices.contains_key(&v) {
+                return Err(anyhow!("Can't find ν{}", v));
+            }
+        }
+        if target == replacement {
+            return Ok(());
+        }
+        let parents: Vec<u32> = self
+            .edges
+            .iter()
+            .filter(|(_, e)| e.to == target && !e.backward())
+            .map(|(i, _)| *i)
+            .sorted()
+            .collect();
+        for e in parents.iter() {
+            if let Some(edge) = self.edges.get_mut(e) {
+                edge.to = replacement;
+            }
+        }
+        let keep: HashSet<u32> = self
+            .layers(0)
+            .concat()
+            .into_iter()
+            .chain(self.layers(replacement).concat())
+            .collect();
+        let dead: HashSet<u32> = self
+            .layers(target)
+            .concat()
+            .into_iter()
+            .filter(|v| !keep.contains(v))
+            .collect();
+        self.vertices.retain(|v, _| !dead.contains(v));
+        self.edges
+            .retain(|_, e| !dead.contains(&e.from) && !dead.contains(&e.to));
+        if let Some(first) = parents.first().and_then(|e| self.edges.get(e)) {
+            let p = first.from;
+            for a in ["ρ", "𝜎"] {
+                if self.edge(replacement, a).is_none() {
+                    let e = self.next_id();
+                    self.bind(e, replacement, p, a)?;
+                }
+            }
+        }
+        if self.verbosity.mutations() {
+            trace!(
+                "#replace_subtree(ν{}, ν{}): {} edges redirected, {} vertices deleted",
+                target,
+                replacement,
+                parents.len(),
+                dead.len()
+            );
+        }
+        self.touch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use crate::data::Data;
+
+#[test]
+fn replaces_simple_subtree() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let p = uni.next_id();
+    uni.add(p)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, p, "p")?;
+    let t = uni.next_id();
+    uni.add(t)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, p, t, "v")?;
+    uni.data(t, Data::from_int(1))?;
+    let t1 = uni.next_id();
+    uni.add(t1)?;
+    let e3 = uni.next_id();
+    uni.bind(e3, t, t1, "a")?;
+    let r = uni.next_id();
+    uni.add(r)?;
+    uni.data(r, Data::from_int(2))?;
+    uni.replace_subtree(t, r)?;
+    assert!(uni.inconsistencies().is_empty());
+    assert_eq!(2, uni.dataize("Φ.p.v")?.as_int()?);
+    assert_eq!(p, uni.find(r, "ρ")?);
+    assert!(!uni.vertices.contains_key(&t));
+    assert!(!uni.vertices.contains_key(&t1));
+    assert!(uni.replace_subtree(t, r).is_err());
+    Ok(())
+}
@@ -0,0 +1,198 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::data::Data;
+use crate::universe::{Edge, Universe};
+use anyhow::{anyhow, Context, Result};
+use itertools::Itertools;
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+
+/// An element of a parsed s-expression.
+enum Sexp {
+    Symbol(String),
+    Text(String),
+    List(Vec<Sexp>),
+}
+
+impl Universe {
+    /// Render the Universe as a list of s-expressions, one per vertex,
+    /// like `(ν0 (x ν3) (y ν5))`, where data is rendered as `(Δ "2a")`.
+    /// Edges refer to vertices by their IDs, that's why cycles are not
+    /// a problem. Atoms are not rendered, since lambdas can't be printed.
+    pub fn to_sexpr(&self) -> String {
+        let mut lines = vec![];
+        for (v, vtx) in self.vertices.iter().sorted_by_key(|(v, _)| **v) {
+            let mut items = vec![format!("ν{}", v)];
+            for (_, e) in self
+                .edges
+                .iter()
+                .filter(|(_, e)| e.from == *v)
+                .sorted_by_key(|(i, _)| **i)
+            {
+                items.push(format!("({} ν{})", symbol(&e.a), e.to));
+            }
+            if let Some(d) = &vtx.data {
+                items.push(format!("(Δ \"{}\")", d.as_hex().replace('-', "")));
+            }
+            lines.push(format!("({})", items.join(" ")));
+        }
+        lines.join("\n")
+    }
+
+    /// Make a new Universe from s-expressions, previously rendered
+    /// by `to_sexpr`. Vertex IDs are preserved, while edges get new IDs.
+    pub fn from_sexpr(text: &str) -> Result<Universe> {
+        let mut uni = Universe::empty();
+        let mut edges = vec![];
+        for sx in sequence(&mut text.chars().peekable(), false)? {
+            let mut items = match sx {
+                Sexp::List(items) => items.into_iter(),
+                _ => return Err(anyhow!("A vertex must be a list")),
+            };
+            let v = match items.next() {
+                Some(Sexp::Symbol(s)) => vertex(&s)?,
+                _ => return Err(anyhow!("A vertex must start with its ID")),
+            };
+            uni.add(v)?;
+            for item in items {
+                let pair = match item {
+                    Sexp::List(pair) => pair,
+                    _ => return Err(anyhow!("An attribute of ν{} must be a list", v)),
+                };
+                match pair.as_slice() {
+                    [Sexp::Symbol(a), Sexp::Text(hex)] if a == "Δ" => {
+                        let bytes =
+                            hex::decode(hex).context(format!("Wrong data in ν{}: '{}'", v, hex))?;
+                        uni.data(v, Data::from_bytes(bytes))?;
+                    }
+                    [Sexp::Symbol(a) | Sexp::Text(a), Sexp::Symbol(to)] => {
+                        edges.push((v, vertex(to)?, a.clone()));
+                    }
+                    _ => return Err(anyhow!("Wrong attribute of ν{}", v)),
+                }
+            }
+        }
+        for (from, to, a) in edges {
+            if !uni.vertices.contains_key(&to) {
+                return Err(anyhow!(
+                    "Edge '{}' of ν{} arrives to unknown ν{}",
+                    a,
+                    from,
+                    to
+                ));
+            }
+            let e = uni.next_id();
+            uni.edges.insert(e, Edge::new(from, to, a));
+        }
+        Ok(uni)
+    }
+}
+
+/// Print a label as a symbol, quoting it if necessary.
+fn symbol(a: &str) -> String {
+    if !a.is_empty() && !a.chars().any(|c| c.is_whitespace() || "()\"\\".contains(c)) {
+        a.to_string()
+    } else {
+        format!("\"{}\"", a.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// Parses `ν5` into `5`.
+fn vertex(s: &str) -> Result<u32> {
+    let tail = s
+        .strip_prefix('ν')
+        .context(format!("Vertex must start with 'ν': '{}'", s))?;
+    u32::from_str(tail).context(format!("Parsing of '{}' failed", s))
+}
+
+/// Parse a sequence of s-expressions, until a closing bracket
+/// (if `nested`) or the end of the text.
+fn sequence(chars: &mut Peekable<Chars>, nested: bool) -> Result<Vec<Sexp>> {
+    let mut items = vec![];
+    loop {
+        match chars.next() {
+            None if nested => return Err(anyhow!("The closing bracket is missing")),
+            None => return Ok(items),
+            Some(')') if nested => return Ok(items),
+            Some(')') => return Err(anyhow!("Unexpected closing bracket")),
+            Some('(') => items.push(Sexp::List(sequence(chars, true)?)),
+            Some('"') => {
+                let mut s = String::new();
+                loop {
+                    match chars.next().context("The closing quote is missing")? {
+                        '"' => break,
+                        '\\' => s.push(chars.next().context("Broken escape sequence")?),
+                        c => s.push(c),
+                    }
+                }
+                items.push(Sexp::Text(s));
+            }
+            Some(c) if c.is_whitespace() => {}
+            Some(c) => {
+                let mut s = c.to_string();
+                while let Some(n) = chars.next_if(|n| !n.is_whitespace() && !"()\"".contains(*n)) {
+                    s.push(n);
+                }
+                items.push(Sexp::Symbol(s));
+            }
+        }
+    }
+}
+
+#[test]
+fn prints_simple_sexpr() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(2, 0, 1, "x")?;
+    uni.data(1, Data::from_bytes(vec![0x2a]))?;
+    assert_eq!("(ν0 (x ν1))\n(ν1 (ρ ν0) (𝜎 ν0) (Δ \"2a\"))", uni.to_sexpr());
+    Ok(())
+}
+
+#[test]
+fn parses_printed_sexpr() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "a-привет")?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, v1, v2, "hello world")?;
+    uni.data(v1, Data::from_str("(\"42\")"))?;
+    let sexpr = uni.to_sexpr();
+    let mut copy = Universe::from_sexpr(sexpr.as_str())?;
+    assert!(copy.inconsistencies().is_empty());
+    assert_eq!(sexpr, copy.to_sexpr());
+    assert!(uni.structurally_eq(&copy));
+    assert_eq!("(\"42\")", copy.dataize("Φ.a-привет")?.as_string()?);
+    Ok(())
+}
+
+#[test]
+fn rejects_broken_sexpr() {
+    assert!(Universe::from_sexpr("(ν0 (x ν1)").is_err());
+    assert!(Universe::from_sexpr("(ν0 (x ν1))").is_err());
+    assert!(Universe::from_sexpr("(ν0 (Δ \"xyz\"))").is_err());
+}
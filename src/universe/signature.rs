@@ -0,0 +1,94 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::data::DataType;
+use crate::universe::Universe;
+use itertools::Itertools;
+
+impl Universe {
+    /// Make a signature of the vertex `v`, which describes its shape:
+    /// sorted labels of its outgoing edges (except `ρ` and `𝜎`), followed
+    /// by `Δ` with the type of its data, if it has any, and by `λ` if it
+    /// has an atom, like `{x, y, Δ:int}`. Vertices with the same attributes
+    /// and types of data have the same signatures. An absent vertex
+    /// has an empty signature `{}`.
+    pub fn signature(&self, v: u32) -> String {
+        let mut items: Vec<String> = self
+            .edges
+            .values()
+            .filter(|e| e.from == v && !e.backward())
+            .map(|e| e.a.clone())
+            .sorted()
+            .dedup()
+            .collect();
+        if let Some(vtx) = self.vertices.get(&v) {
+            if let Some(d) = &vtx.data {
+                let kind = match d.kind() {
+                    DataType::Int => "int",
+                    DataType::Float => "float",
+                    DataType::Bool => "bool",
+                    DataType::String => "string",
+                    DataType::Unknown => "bytes",
+                };
+                items.push(format!("Δ:{}", kind));
+            }
+            if vtx.lambda.is_some() {
+                items.push("λ".to_string());
+            }
+        }
+        format!("{{{}}}", items.join(", "))
+    }
+}
+
+#[cfg(test)]
+use crate::data::Data;
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn makes_signatures() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let mut kids = vec![];
+    for a in ["first", "second"] {
+        let v = uni.next_id();
+        uni.add(v)?;
+        let e = uni.next_id();
+        uni.bind(e, 0, v, a)?;
+        for k in ["y", "x"] {
+            let v2 = uni.next_id();
+            uni.add(v2)?;
+            let e2 = uni.next_id();
+            uni.bind(e2, v, v2, k)?;
+        }
+        uni.data(v, Data::from_int(42))?;
+        kids.push(v);
+    }
+    assert_eq!("{x, y, Δ:int}", uni.signature(kids[0]));
+    assert_eq!(uni.signature(kids[0]), uni.signature(kids[1]));
+    uni.data(kids[1], Data::from_str("hi"))?;
+    assert_eq!("{x, y, Δ:string}", uni.signature(kids[1]));
+    assert_eq!("{first, second}", uni.signature(0));
+    let x = uni.find(kids[0], "x")?;
+    assert_eq!("{}", uni.signature(x));
+    assert_eq!("{}", uni.signature(42));
+    Ok(())
+}
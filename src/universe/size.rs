@@ -0,0 +1,71 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::{Edge, Lambda, Universe, Vertex};
+use std::mem::size_of;
+
+impl Universe {
+    /// Estimate the number of bytes the Universe occupies in memory.
+    /// Counted are the capacities of all internal maps, the data of
+    /// vertices, the labels of edges, and the names of atoms. The
+    /// estimate is not exact, since the bookkeeping overhead of the
+    /// maps is ignored, but it grows together with the graph.
+    pub fn size_in_bytes(&self) -> usize {
+        let mut total = size_of::<Universe>();
+        total += self.vertices.capacity() * (size_of::<u32>() + size_of::<Vertex>());
+        total += self.edges.capacity() * (size_of::<u32>() + size_of::<Edge>());
+        total += self.atoms.capacity() * (size_of::<String>() + size_of::<Lambda>());
+        total += self.hits.capacity() * (size_of::<u32>() + size_of::<usize>());
+        total += self
+            .vertices
+            .values()
+            .map(|v| v.search.capacity() + v.data.as_ref().map_or(0, |d| d.len()))
+            .sum::<usize>();
+        total += self.edges.values().map(|e| e.a.capacity()).sum::<usize>();
+        total += self.atoms.keys().map(|a| a.capacity()).sum::<usize>();
+        total
+    }
+}
+
+#[cfg(test)]
+use crate::data::Data;
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn grows_together_with_graph() -> Result<()> {
+    let mut uni = Universe::empty();
+    let empty = uni.size_in_bytes();
+    uni.add(0)?;
+    let small = uni.size_in_bytes();
+    assert!(small > empty);
+    for _ in 0..100 {
+        let v = uni.next_id();
+        uni.add(v)?;
+        let e = uni.next_id();
+        uni.bind(e, 0, v, format!("a{}", v).as_str())?;
+    }
+    let big = uni.size_in_bytes();
+    assert!(big > small);
+    uni.data(0, Data::from_bytes(vec![0; 1000]))?;
+    assert!(uni.size_in_bytes() >= big + 1000);
+    Ok(())
+}
@@ -0,0 +1,103 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use anyhow::{anyhow, Context, Result};
+use log::trace;
+
+impl Universe {
+    /// Replaces the edge `e` (from `v1` to `v2`) with a new vertex `v3`
+    /// in the middle: the edge `e` now goes from `v1` to `v3` and is
+    /// labeled as `label_in`, while a new edge goes from `v3` to `v2` and is
+    /// labeled as `label_out`. The `ρ` and `𝜎` edges of `v2`, which
+    /// pointed to `v1`, are moved to `v3`. Returns the ID of `v3`.
+    pub fn split_edge(&mut self, e: u32, label_in: &str, label_out: &str) -> Result<u32> {
+        let edge = self.edges.get(&e).context(format!("Can't find ε{}", e))?;
+        let v1 = edge.from;
+        let v2 = edge.to;
+        if self
+            .edges
+            .iter()
+            .any(|(i, x)| *i != e && x.from == v1 && x.a == label_in)
+        {
+            return Err(anyhow!("Edge '{}' already exists in ν{}", label_in, v1));
+        }
+        let v3 = self.next_id();
+        self.add(v3)?;
+        self.edges.remove(&e);
+        for x in self
+            .edges
+            .values_mut()
+            .filter(|x| x.from == v2 && x.to == v1 && x.backward())
+        {
+            x.to = v3;
+        }
+        self.bind(e, v1, v3, label_in)?;
+        let e2 = self.next_id();
+        self.bind(e2, v3, v2, label_out)?;
+        if self.verbosity.mutations() {
+            trace!(
+                "#split_edge(ε{}, '{}', '{}'): ν{}-ε{}>ν{} restructured as ν{}-ε{}>ν{}-ε{}>ν{}",
+                e,
+                label_in,
+                label_out,
+                v1,
+                e,
+                v2,
+                v1,
+                e,
+                v3,
+                e2,
+                v2
+            );
+        }
+        self.touch();
+        Ok(v3)
+    }
+}
+
+#[cfg(test)]
+use crate::data::Data;
+
+#[test]
+fn splits_simple_edge() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "x")?;
+    uni.data(v1, Data::from_int(42))?;
+    let v2 = uni.split_edge(e1, "x", "y")?;
+    assert!(uni.inconsistencies().is_empty());
+    assert_eq!(v2, uni.find(0, "x")?);
+    assert_eq!(v2, uni.find(v1, "ρ")?);
+    assert_eq!(0, uni.find(v2, "ρ")?);
+    assert_eq!(42, uni.dataize("Φ.x.y")?.as_int()?);
+    Ok(())
+}
+
+#[test]
+fn fails_to_split_absent_edge() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    assert!(uni.split_edge(42, "x", "y").is_err());
+    Ok(())
+}
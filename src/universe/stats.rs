@@ -0,0 +1,178 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+/// Summary numbers of a Universe, see `Universe::stats`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Stats {
+    pub vertices: usize,
+    pub edges: usize,
+    pub atoms: usize,
+    pub vertices_with_data: usize,
+    pub max_depth: usize,
+}
+
+impl Universe {
+    /// Count vertices, edges, atoms, and vertices with data, and find
+    /// the depth of the graph. The depth is the length of the longest
+    /// path from `ν0`, along all edges except `ρ` and `𝜎` ones, after
+    /// dropping the edges back to the vertices on the current path of
+    /// a depth-first search, which visits kids in the order of their IDs.
+    /// Thus, cycles are broken and it takes linear time. If there are
+    /// no cycles, it's exactly the longest path.
+    pub fn stats(&self) -> Stats {
+        let mut depths = HashMap::new();
+        let max_depth = if self.vertices.contains_key(&0) {
+            self.depth(0, &mut HashSet::new(), &mut depths)
+        } else {
+            0
+        };
+        Stats {
+            vertices: self.vertices.len(),
+            edges: self.edges.len(),
+            atoms: self
+                .vertices
+                .values()
+                .filter(|v| v.lambda.is_some())
+                .count(),
+            vertices_with_data: self.vertices.values().filter(|v| v.data.is_some()).count(),
+            max_depth,
+        }
+    }
+
+    /// The depth of `v`, skipping the kids on the current path `seen`.
+    /// The depths of all finished vertices are kept in `depths`.
+    fn depth(&self, v: u32, seen: &mut HashSet<u32>, depths: &mut HashMap<u32, usize>) -> usize {
+        seen.insert(v);
+        let kids: Vec<u32> = self
+            .edges
+            .values()
+            .filter(|e| e.from == v && !e.backward() && self.vertices.contains_key(&e.to))
+            .map(|e| e.to)
+            .sorted()
+            .dedup()
+            .collect();
+        let mut max = 0;
+        for k in kids {
+            if seen.contains(&k) {
+                continue;
+            }
+            let d = match depths.get(&k) {
+                Some(d) => *d,
+                None => self.depth(k, seen, depths),
+            };
+            max = max.max(1 + d);
+        }
+        seen.remove(&v);
+        depths.insert(v, max);
+        max
+    }
+}
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn counts_int_and_rand() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "int")?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, 0, v2, "rand")?;
+    let e3 = uni.next_id();
+    uni.bind(e3, 0, v2, "x")?;
+    uni.register("rand", super::rand);
+    uni.atom(v2, "rand")?;
+    assert_eq!(
+        Stats {
+            vertices: 3,
+            edges: 7,
+            atoms: 1,
+            vertices_with_data: 0,
+            max_depth: 1
+        },
+        uni.stats()
+    );
+    Ok(())
+}
+
+#[test]
+fn measures_depth_with_cycles() -> Result<()> {
+    let mut uni = Universe::empty();
+    for v in 0..4 {
+        uni.add(v)?;
+    }
+    uni.bind(100, 0, 1, "a")?;
+    uni.bind(101, 1, 2, "b")?;
+    uni.bind(102, 2, 3, "c")?;
+    uni.bind(103, 3, 1, "d")?;
+    assert_eq!(3, uni.stats().max_depth);
+    Ok(())
+}
+
+#[test]
+fn measures_depth_regardless_of_order() -> Result<()> {
+    for _ in 0..20 {
+        let mut uni = Universe::empty();
+        for v in 0..5 {
+            uni.add(v)?;
+        }
+        uni.bind(100, 0, 1, "a")?;
+        uni.bind(101, 0, 2, "b")?;
+        uni.bind(102, 1, 2, "c")?;
+        uni.bind(103, 2, 1, "d")?;
+        uni.bind(104, 1, 3, "e")?;
+        uni.bind(105, 3, 4, "f")?;
+        assert_eq!(3, uni.stats().max_depth);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+use crate::universe::Edge;
+
+#[test]
+fn measures_dense_cyclic_graph() -> Result<()> {
+    let mut uni = Universe::empty();
+    let total = 40;
+    for v in 0..total {
+        uni.add(v)?;
+    }
+    for from in 0..total {
+        for to in 0..total {
+            if from != to {
+                uni.edges.insert(
+                    1000 + from * total + to,
+                    Edge::new(from, to, format!("a{}", to)),
+                );
+            }
+        }
+    }
+    assert_eq!(total as usize - 1, uni.stats().max_depth);
+    Ok(())
+}
@@ -0,0 +1,193 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use itertools::Itertools;
+use std::collections::{HashMap, VecDeque};
+
+impl Universe {
+    /// Check whether the `other` Universe has the same structure as this
+    /// one, no matter what IDs its vertices and edges have. Both graphs are
+    /// traversed from `ν0`, matching vertices by the labels of their edges,
+    /// in sorted order. Vertices not reachable from `ν0` are matched too,
+    /// trying all candidates, so it may be slow for many such vertices.
+    /// Matching vertices must have the same data and both have or both
+    /// not have atoms. Every vertex must have a match.
+    pub fn structurally_eq(&self, other: &Universe) -> bool {
+        if self.vertices.len() != other.vertices.len() || self.edges.len() != other.edges.len() {
+            return false;
+        }
+        if self.vertices.contains_key(&0) != other.vertices.contains_key(&0) {
+            return false;
+        }
+        let mut there: HashMap<u32, u32> = HashMap::new();
+        let mut back: HashMap<u32, u32> = HashMap::new();
+        if self.vertices.contains_key(&0) && !self.pair(other, 0, 0, &mut there, &mut back) {
+            return false;
+        }
+        self.pair_rest(other, &mut there, &mut back)
+    }
+
+    /// Match the vertices, which are not matched yet, trying every
+    /// vertex of the `other` Universe for the first of them.
+    fn pair_rest(
+        &self,
+        other: &Universe,
+        there: &mut HashMap<u32, u32>,
+        back: &mut HashMap<u32, u32>,
+    ) -> bool {
+        let a = match self
+            .vertices
+            .keys()
+            .filter(|v| !there.contains_key(v))
+            .min()
+        {
+            Some(a) => *a,
+            None => return true,
+        };
+        for b in other
+            .vertices
+            .keys()
+            .filter(|v| !back.contains_key(v))
+            .sorted()
+        {
+            let (mut t, mut k) = (there.clone(), back.clone());
+            if self.pair(other, a, *b, &mut t, &mut k) && self.pair_rest(other, &mut t, &mut k) {
+                *there = t;
+                *back = k;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Match the vertex `a` with the vertex `b` of the `other` Universe,
+    /// and then all vertices reachable from them, by the labels of edges.
+    fn pair(
+        &self,
+        other: &Universe,
+        a: u32,
+        b: u32,
+        there: &mut HashMap<u32, u32>,
+        back: &mut HashMap<u32, u32>,
+    ) -> bool {
+        there.insert(a, b);
+        back.insert(b, a);
+        let mut queue = VecDeque::from([(a, b)]);
+        while let Some((a, b)) = queue.pop_front() {
+            let (va, vb) = (&self.vertices[&a], &other.vertices[&b]);
+            if va.data != vb.data || va.lambda.is_some() != vb.lambda.is_some() {
+                return false;
+            }
+            let ka = self.sorted_kids(a);
+            let kb = other.sorted_kids(b);
+            if ka.len() != kb.len() {
+                return false;
+            }
+            for ((la, ta), (lb, tb)) in ka.into_iter().zip(kb) {
+                if la != lb {
+                    return false;
+                }
+                match (there.get(&ta), back.get(&tb)) {
+                    (None, None) => {
+                        if !self.vertices.contains_key(&ta) || !other.vertices.contains_key(&tb) {
+                            return false;
+                        }
+                        there.insert(ta, tb);
+                        back.insert(tb, ta);
+                        queue.push_back((ta, tb));
+                    }
+                    (Some(x), Some(y)) if *x == tb && *y == ta => {}
+                    _ => return false,
+                }
+            }
+        }
+        true
+    }
+
+    /// Labels of all edges departing from `v`, together with
+    /// the vertices they arrive to, sorted by labels.
+    fn sorted_kids(&self, v: u32) -> Vec<(&str, u32)> {
+        self.edges
+            .values()
+            .filter(|e| e.from == v)
+            .map(|e| (e.a.as_str(), e.to))
+            .sorted_by_key(|(a, _)| *a)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+use crate::data::Data;
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[cfg(test)]
+fn tree(order: &[&str], last: &str) -> Result<Universe> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    for a in order {
+        let v = uni.next_id();
+        uni.add(v)?;
+        let e = uni.next_id();
+        uni.bind(e, 0, v, a)?;
+        uni.data(v, Data::from_str(a))?;
+    }
+    let v = uni.find(0, order[0])?;
+    let kid = uni.next_id();
+    uni.add(kid)?;
+    let e = uni.next_id();
+    uni.bind(e, v, kid, last)?;
+    Ok(uni)
+}
+
+#[test]
+fn compares_structures() -> Result<()> {
+    let first = tree(&["x", "y", "z"], "k")?;
+    let second = tree(&["x", "z", "y"], "k")?;
+    assert!(first.structurally_eq(&second));
+    assert!(first.structurally_eq(&first));
+    let third = tree(&["x", "z", "y"], "m")?;
+    assert!(!first.structurally_eq(&third));
+    let fourth = tree(&["y", "z", "x"], "k")?;
+    assert!(!first.structurally_eq(&fourth));
+    Ok(())
+}
+
+#[cfg(test)]
+fn islands(data: &[i64]) -> Result<Universe> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    for d in data {
+        let v = uni.next_id();
+        uni.add(v)?;
+        uni.data(v, Data::from_int(*d))?;
+    }
+    Ok(uni)
+}
+
+#[test]
+fn compares_unreachable_vertices() -> Result<()> {
+    assert!(!islands(&[1])?.structurally_eq(&islands(&[2])?));
+    assert!(islands(&[1, 2])?.structurally_eq(&islands(&[2, 1])?));
+    assert!(!islands(&[1, 1])?.structurally_eq(&islands(&[1, 2])?));
+    Ok(())
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use std::collections::{HashSet, VecDeque};
+use std::str::FromStr;
+
+impl Universe {
+    /// Make a copy of the Universe with only those vertices, which are
+    /// reachable from `root` by any edges, including `ρ` and `𝜎` ones,
+    /// and the edges between them, or by `ν` and `Φ` jumps in the locators
+    /// of search atoms. Vertex and edge IDs are preserved.
+    pub fn subgraph(&self, root: u32) -> Universe {
+        let mut uni = Universe::empty();
+        uni.atoms = self.atoms.clone();
+        uni.tick = self.tick;
+        uni.verbosity = self.verbosity;
+        uni.max_deref = self.max_deref;
+        uni.auto_repair = self.auto_repair;
+        uni.warn_dead_ends = self.warn_dead_ends;
+        if !self.vertices.contains_key(&root) {
+            return uni;
+        }
+        let mut seen = HashSet::from([root]);
+        let mut queue = VecDeque::from([root]);
+        while let Some(v) = queue.pop_front() {
+            let jumps = self.vertices[&v].search.split('.').filter_map(|k| {
+                if k == "Φ" {
+                    Some(0)
+                } else {
+                    k.strip_prefix('ν').and_then(|n| u32::from_str(n).ok())
+                }
+            });
+            let hops = self.edges.values().filter(|e| e.from == v).map(|e| e.to);
+            for to in jumps.chain(hops) {
+                if self.vertices.contains_key(&to) && seen.insert(to) {
+                    queue.push_back(to);
+                }
+            }
+        }
+        for v in seen.iter() {
+            uni.vertices.insert(*v, self.vertices[v].clone());
+        }
+        for (i, e) in self.edges.iter() {
+            if seen.contains(&e.from) && seen.contains(&e.to) {
+                uni.edges.insert(*i, e.clone());
+            }
+        }
+        uni
+    }
+}
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn drops_unreachable_island() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(100, 0, 1, "x")?;
+    uni.add(2)?;
+    uni.bind(101, 1, 2, "y")?;
+    uni.add(3)?;
+    uni.add(4)?;
+    uni.bind(102, 3, 4, "z")?;
+    let sub = uni.subgraph(0);
+    assert_eq!(3, sub.vertices.len());
+    assert!(!sub.vertices.contains_key(&3));
+    assert!(!sub.vertices.contains_key(&4));
+    assert!(!sub.edges.contains_key(&102));
+    assert!(sub.inconsistencies().is_empty());
+    assert_eq!(2, sub.clone().find(0, "x.y")?);
+    assert_eq!(
+        5,
+        uni.subgraph(2).vertices.len() + uni.subgraph(4).vertices.len()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+use crate::data::Data;
+
+#[test]
+fn keeps_alias_target() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.bind(100, 0, 1, "x")?;
+    uni.atom(1, "S/ν2")?;
+    uni.add(2)?;
+    uni.data(2, Data::from_int(42))?;
+    let mut sub = uni.subgraph(0);
+    assert!(sub.vertices.contains_key(&2));
+    assert_eq!(42, sub.dataize("Φ.x.Δ")?.as_int()?);
+    Ok(())
+}
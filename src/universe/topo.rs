@@ -0,0 +1,108 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::Universe;
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+impl Universe {
+    /// Order all vertices so that every vertex goes before the vertices
+    /// its edges point to, ignoring `ρ` and `𝜎` edges. Among vertices
+    /// that are ready at the same time, smaller IDs go first. If there is
+    /// a cycle, an error is returned.
+    pub fn topo_order(&self) -> Result<Vec<u32>> {
+        let mut degrees: HashMap<u32, usize> = self.vertices.keys().map(|v| (*v, 0)).collect();
+        let forward: Vec<(u32, u32)> = self
+            .edges
+            .values()
+            .filter(|e| {
+                !e.backward()
+                    && self.vertices.contains_key(&e.from)
+                    && self.vertices.contains_key(&e.to)
+            })
+            .map(|e| (e.from, e.to))
+            .collect();
+        for (_, to) in forward.iter() {
+            *degrees.get_mut(to).unwrap() += 1;
+        }
+        let mut order = vec![];
+        let mut ready: Vec<u32> = degrees
+            .iter()
+            .filter(|(_, d)| **d == 0)
+            .map(|(v, _)| *v)
+            .sorted()
+            .collect();
+        while !ready.is_empty() {
+            let v = ready.remove(0);
+            order.push(v);
+            for (_, to) in forward.iter().filter(|(from, _)| *from == v) {
+                let d = degrees.get_mut(to).unwrap();
+                *d -= 1;
+                if *d == 0 {
+                    ready.push(*to);
+                }
+            }
+            ready.sort();
+        }
+        if order.len() < self.vertices.len() {
+            let rest = degrees
+                .iter()
+                .filter(|(_, d)| **d > 0)
+                .map(|(v, _)| format!("ν{}", v))
+                .sorted()
+                .join(", ");
+            return Err(anyhow!("There is a cycle among {}", rest));
+        }
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+use crate::universe::Edge;
+
+#[test]
+fn orders_parents_first() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.add(2)?;
+    uni.add(3)?;
+    uni.bind(101, 0, 2, "x")?;
+    uni.bind(102, 2, 1, "y")?;
+    uni.bind(103, 0, 3, "z")?;
+    uni.bind(104, 3, 1, "w")?;
+    assert_eq!(vec![0, 2, 3, 1], uni.topo_order()?);
+    Ok(())
+}
+
+#[test]
+fn rejects_cycles() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    uni.add(1)?;
+    uni.add(2)?;
+    uni.bind(101, 0, 1, "x")?;
+    uni.bind(102, 1, 2, "y")?;
+    uni.edges.insert(103, Edge::new(2, 1, "z".to_string()));
+    let err = uni.topo_order().unwrap_err().to_string();
+    assert!(err.contains("ν1, ν2"), "{}", err);
+    Ok(())
+}
@@ -0,0 +1,72 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::universe::{Edge, Universe};
+
+impl Universe {
+    /// Make a copy of the Universe with the direction of every edge
+    /// flipped, while labels, data, and atoms stay intact. Edge and
+    /// vertex IDs are preserved. Mind that `ρ` and `𝜎` edges keep their
+    /// labels, but point from parents to kids in the copy, while all
+    /// other edges point from kids to parents.
+    pub fn transpose(&self) -> Universe {
+        let mut uni = Universe::empty();
+        uni.atoms = self.atoms.clone();
+        uni.tick = self.tick;
+        uni.verbosity = self.verbosity;
+        uni.max_deref = self.max_deref;
+        uni.auto_repair = self.auto_repair;
+        uni.warn_dead_ends = self.warn_dead_ends;
+        for (v, vtx) in self.vertices.iter() {
+            uni.vertices.insert(*v, vtx.clone());
+        }
+        for (i, e) in self.edges.iter() {
+            uni.edges.insert(*i, Edge::new(e.to, e.from, e.a.clone()));
+        }
+        uni
+    }
+}
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn reaches_backwards_in_transposed() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    let v1 = uni.next_id();
+    uni.add(v1)?;
+    let e1 = uni.next_id();
+    uni.bind(e1, 0, v1, "x")?;
+    let v2 = uni.next_id();
+    uni.add(v2)?;
+    let e2 = uni.next_id();
+    uni.bind(e2, v1, v2, "y")?;
+    let v3 = uni.next_id();
+    uni.add(v3)?;
+    let e3 = uni.next_id();
+    uni.bind(e3, 0, v3, "z")?;
+    let mut t = uni.transpose();
+    assert_eq!(vec![vec![v2], vec![v1], vec![0]], t.layers(v2));
+    assert_eq!(vec![vec![0], vec![v1, v3], vec![v2]], uni.layers(0));
+    assert_eq!(0, t.find(v2, "y.x")?);
+    assert_eq!(v2, t.find(v1, "ρ")?);
+    Ok(())
+}
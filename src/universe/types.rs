@@ -0,0 +1,69 @@
+// Copyright (c) 2022 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::data::DataType;
+use crate::universe::Universe;
+use std::collections::BTreeMap;
+
+impl Universe {
+    /// Group IDs of all vertices with data by the type of their data.
+    /// Data made from raw bytes goes under `DataType::Unknown`. Each
+    /// list of IDs is sorted.
+    pub fn group_by_type(&self) -> BTreeMap<DataType, Vec<u32>> {
+        let mut groups: BTreeMap<DataType, Vec<u32>> = BTreeMap::new();
+        for (v, vtx) in self.vertices.iter() {
+            if let Some(d) = &vtx.data {
+                groups.entry(d.kind()).or_default().push(*v);
+            }
+        }
+        for ids in groups.values_mut() {
+            ids.sort();
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+use crate::data::Data;
+
+#[cfg(test)]
+use anyhow::Result;
+
+#[test]
+fn groups_vertices_by_type() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add(0)?;
+    for d in [
+        Data::from_int(1),
+        Data::from_str("hello"),
+        Data::from_int(2),
+        Data::from_hex("ff".to_string()),
+    ] {
+        let v = uni.next_id();
+        uni.add(v)?;
+        uni.data(v, d)?;
+    }
+    let groups = uni.group_by_type();
+    assert_eq!(Some(&vec![1, 3]), groups.get(&DataType::Int));
+    assert_eq!(Some(&vec![2]), groups.get(&DataType::String));
+    assert_eq!(Some(&vec![4]), groups.get(&DataType::Unknown));
+    assert_eq!(None, groups.get(&DataType::Float));
+    Ok(())
+}